@@ -30,8 +30,8 @@ use std::{borrow::Cow, collections::HashMap, pin::Pin, sync::Arc};
 use node_primitives::Block;
 use node_testing::bench::{BenchDb, BlockType, DatabaseType, KeyTypes, Profile};
 use sc_transaction_pool_api::{
-	ImportNotificationStream, PoolFuture, PoolStatus, ReadyTransactions, TransactionFor,
-	TransactionSource, TransactionStatusStreamFor, TxHash,
+	ImportNotificationStream, PoolFuture, PoolLimits, PoolStatus, ReadyTransactions,
+	TransactionFor, TransactionPriority, TransactionSource, TransactionStatusStreamFor, TxHash,
 };
 use sp_consensus::{Environment, Proposer};
 use sp_inherents::InherentDataProvider;
@@ -265,6 +265,15 @@ impl sc_transaction_pool_api::TransactionPool for Transactions {
 		unimplemented!()
 	}
 
+	fn submit_local_with_priority(
+		&self,
+		_at: &BlockId<Self::Block>,
+		_xt: TransactionFor<Self>,
+		_priority: TransactionPriority,
+	) -> PoolFuture<TxHash<Self>, Self::Error> {
+		unimplemented!()
+	}
+
 	fn ready_at(
 		&self,
 		_at: NumberFor<Self::Block>,
@@ -292,6 +301,10 @@ impl sc_transaction_pool_api::TransactionPool for Transactions {
 		unimplemented!()
 	}
 
+	fn limits(&self) -> PoolLimits {
+		unimplemented!()
+	}
+
 	fn import_notification_stream(&self) -> ImportNotificationStream<TxHash<Self>> {
 		unimplemented!()
 	}
@@ -50,6 +50,7 @@ use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 use sp_consensus::SelectChain;
 use sp_consensus_babe::BabeApi;
 use sp_keystore::SyncCryptoStorePtr;
+use sp_npos_elections::VoteWeight;
 
 /// Extra dependencies for BABE.
 pub struct BabeDeps {
@@ -108,6 +109,7 @@ where
 		+ Send
 		+ 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
+	C::Api: pallet_bags_list_rpc::BagsListRuntimeApi<Block, AccountId, VoteWeight>,
 	C::Api: pallet_mmr_rpc::MmrRuntimeApi<
 		Block,
 		<Block as sp_runtime::traits::Block>::Hash,
@@ -121,6 +123,7 @@ where
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashFor<Block>>,
 {
+	use pallet_bags_list_rpc::{BagsList, BagsListApiServer};
 	use pallet_mmr_rpc::{Mmr, MmrApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
@@ -154,6 +157,7 @@ where
 	// These RPCs should use an asynchronous caller instead.
 	io.merge(Mmr::new(client.clone()).into_rpc())?;
 	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(BagsList::new(client.clone()).into_rpc())?;
 	io.merge(
 		Babe::new(
 			client.clone(),
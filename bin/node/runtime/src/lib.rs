@@ -746,6 +746,10 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	/// of each node is the staking pallet.
 	type ScoreProvider = Staking;
 	type BagThresholds = BagThresholds;
+	type MaxReasonableWeight = ();
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type OnRemoveGuard = ();
+	type NodeMetadata = ();
 	type Score = VoteWeight;
 	type WeightInfo = pallet_bags_list::weights::SubstrateWeight<Runtime>;
 }
@@ -1122,12 +1126,19 @@ impl pallet_bounties::Config for Runtime {
 
 parameter_types! {
 	pub const ChildBountyValueMinimum: Balance = 1 * DOLLARS;
+	pub const ChildBountyMaxCuratorFeeRatio: Permill = Permill::from_percent(50);
+	pub const MaxChildBountyValueRatio: Permill = Permill::from_percent(80);
+	pub const ChildCuratorDepositMin: Balance = 2 * DOLLARS;
 }
 
 impl pallet_child_bounties::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = ConstU32<5>;
 	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type MaxCuratorFeeRatio = ChildBountyMaxCuratorFeeRatio;
+	type MaxChildBountyValueRatio = MaxChildBountyValueRatio;
+	type MaxAwardBeneficiaries = ConstU32<10>;
+	type ChildCuratorDepositMin = ChildCuratorDepositMin;
 	type WeightInfo = pallet_child_bounties::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1722,6 +1733,8 @@ type Migrations = (
 	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_child_bounties::migrations::v1::MigrateToV1<Runtime>,
+	pallet_bags_list::migrations::AddMetadata<Runtime, VoterBagsListInstance>,
 );
 
 /// MMR helper types.
@@ -1829,6 +1842,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl sp_block_builder::DryRunApi<Block> for Runtime {
+		fn dry_run_extrinsic(
+			extrinsic: <Block as BlockT>::Extrinsic,
+			block_hash: <Block as BlockT>::Hash,
+		) -> (ApplyExtrinsicResult, Vec<u8>) {
+			// Set up the child block the extrinsic would be included in, like
+			// `Executive::validate_transaction` does. This also clears the events.
+			System::initialize(&(System::block_number() + 1), &block_hash, &Default::default());
+			System::note_finished_initialize();
+			let result = Executive::apply_extrinsic(extrinsic);
+			(result, System::read_events_no_consensus().encode())
+		}
+	}
+
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
 		fn validate_transaction(
 			source: TransactionSource,
@@ -1881,12 +1908,64 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_child_bounties_runtime_api::ChildBountiesApi<Block, AccountId, BlockNumber, Balance> for Runtime {
+		fn active_child_bounties_of_curator(
+			curator: AccountId,
+		) -> Vec<(pallet_bounties::BountyIndex, pallet_bounties::BountyIndex)> {
+			ChildBounties::active_child_bounties_of_curator(&curator)
+		}
+
+		fn child_bounty_unlock_at(
+			parent_bounty_id: pallet_bounties::BountyIndex,
+			child_bounty_id: pallet_bounties::BountyIndex,
+		) -> Option<BlockNumber> {
+			ChildBounties::child_bounty_unlock_at(parent_bounty_id, child_bounty_id)
+		}
+
+		fn claimable_child_bounties(
+			now: BlockNumber,
+			limit: u32,
+		) -> Vec<(pallet_bounties::BountyIndex, pallet_bounties::BountyIndex, AccountId)> {
+			ChildBounties::claimable_child_bounties(now, limit)
+		}
+
+		fn child_bounty_account_balance(
+			parent_bounty_id: pallet_bounties::BountyIndex,
+			child_bounty_id: pallet_bounties::BountyIndex,
+		) -> Balance {
+			ChildBounties::child_bounty_account_balance(parent_bounty_id, child_bounty_id)
+		}
+
+		fn parent_of_child(
+			child_bounty_id: pallet_bounties::BountyIndex,
+		) -> Option<pallet_bounties::BountyIndex> {
+			ChildBounties::parent_of_child(child_bounty_id)
+		}
+
+		fn pending_child_curator_fees() -> Balance {
+			ChildBounties::pending_child_curator_fees()
+		}
+
+		fn child_bounties_stats() -> pallet_child_bounties::ChildBountiesStats<Balance> {
+			ChildBounties::child_bounties_stats()
+		}
+	}
+
 	impl pallet_nomination_pools_runtime_api::NominationPoolsApi<Block, AccountId, Balance> for Runtime {
 		fn pending_rewards(member_account: AccountId) -> Balance {
 			NominationPools::pending_rewards(member_account).unwrap_or_default()
 		}
 	}
 
+	impl pallet_bags_list_rpc_runtime_api::BagsListApi<Block, AccountId, VoteWeight> for Runtime {
+		fn bag_distribution(
+			start: Option<AccountId>,
+			limit: u32,
+		) -> Option<(Vec<(VoteWeight, u32)>, Option<AccountId>)> {
+			VoterList::bag_distribution(start.as_ref(), limit).ok()
+		}
+	}
+
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
 		fn configuration() -> sp_consensus_babe::BabeConfiguration {
 			let epoch_config = Babe::epoch_config().unwrap_or(BABE_GENESIS_EPOCH_CONFIG);
@@ -2145,6 +2224,35 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl sp_session::ValidatorSessionKeys<Block> for Runtime {
+		fn validator_session_keys() -> Vec<Vec<(Vec<u8>, KeyTypeId)>> {
+			// `NextKeys` and `QueuedKeys` only take effect in a later session, the keys in use are
+			// those handed to the session handlers, which keep them in the order of the validators.
+			let grandpa = Grandpa::grandpa_authorities();
+			let babe = Babe::authorities();
+			let im_online = ImOnline::keys();
+			let authority_discovery = AuthorityDiscovery::current_authorities();
+			grandpa
+				.into_iter()
+				.zip(babe.iter())
+				.zip(im_online.iter())
+				.zip(authority_discovery.iter())
+				.map(|(((grandpa, babe), im_online), authority_discovery)| {
+					let keys = SessionKeys {
+						grandpa: grandpa.0,
+						babe: babe.0.clone(),
+						im_online: im_online.clone(),
+						authority_discovery: authority_discovery.clone(),
+					};
+					SessionKeys::key_ids()
+						.iter()
+						.map(|&id| (keys.get_raw(id).to_vec(), id))
+						.collect()
+				})
+				.collect()
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade() -> (Weight, Weight) {
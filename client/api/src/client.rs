@@ -285,6 +285,37 @@ impl fmt::Display for UsageInfo {
 	}
 }
 
+impl UsageInfo {
+	/// Flatten the statistics into labeled values, e.g. to feed a metrics registry.
+	///
+	/// Memory sizes are in bytes. A missing pruning window is reported as zero.
+	pub fn to_metrics(&self) -> Vec<(&'static str, f64)> {
+		let memory = &self.memory;
+		let io = &self.io;
+		vec![
+			("memory.state_cache", memory.state_cache.as_bytes() as f64),
+			("memory.database_cache", memory.database_cache.as_bytes() as f64),
+			("memory.state_db.non_canonical", memory.state_db.non_canonical.as_bytes() as f64),
+			(
+				"memory.state_db.pruning",
+				memory.state_db.pruning.unwrap_or_default().as_bytes() as f64,
+			),
+			("memory.state_db.pinned", memory.state_db.pinned.as_bytes() as f64),
+			("io.transactions", io.transactions as f64),
+			("io.bytes_read", io.bytes_read as f64),
+			("io.bytes_written", io.bytes_written as f64),
+			("io.writes", io.writes as f64),
+			("io.reads", io.reads as f64),
+			("io.average_transaction_size", io.average_transaction_size as f64),
+			("io.state_reads", io.state_reads as f64),
+			("io.state_reads_cache", io.state_reads_cache as f64),
+			("io.state_writes", io.state_writes as f64),
+			("io.state_writes_cache", io.state_writes_cache as f64),
+			("io.state_writes_nodes", io.state_writes_nodes as f64),
+		]
+	}
+}
+
 /// Summary of an imported block
 #[derive(Clone, Debug)]
 pub struct BlockImportNotification<Block: BlockT> {
@@ -358,3 +389,62 @@ impl<B: BlockT> From<ImportSummary<B>> for BlockImportNotification<B> {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn usage_info_to_metrics_works() {
+		let info = UsageInfo {
+			memory: MemoryInfo {
+				state_cache: MemorySize::from_bytes(1),
+				database_cache: MemorySize::from_bytes(2),
+				state_db: StateDbMemoryInfo {
+					non_canonical: MemorySize::from_bytes(3),
+					pruning: Some(MemorySize::from_bytes(4)),
+					pinned: MemorySize::from_bytes(5),
+				},
+			},
+			io: IoInfo {
+				transactions: 6,
+				bytes_read: 7,
+				bytes_written: 8,
+				writes: 9,
+				reads: 10,
+				average_transaction_size: 11,
+				state_reads: 12,
+				state_reads_cache: 13,
+				state_writes: 14,
+				state_writes_cache: 15,
+				state_writes_nodes: 16,
+			},
+		};
+
+		assert_eq!(
+			info.to_metrics(),
+			vec![
+				("memory.state_cache", 1.0),
+				("memory.database_cache", 2.0),
+				("memory.state_db.non_canonical", 3.0),
+				("memory.state_db.pruning", 4.0),
+				("memory.state_db.pinned", 5.0),
+				("io.transactions", 6.0),
+				("io.bytes_read", 7.0),
+				("io.bytes_written", 8.0),
+				("io.writes", 9.0),
+				("io.reads", 10.0),
+				("io.average_transaction_size", 11.0),
+				("io.state_reads", 12.0),
+				("io.state_reads_cache", 13.0),
+				("io.state_writes", 14.0),
+				("io.state_writes_cache", 15.0),
+				("io.state_writes_nodes", 16.0),
+			]
+		);
+
+		// Without a pruning window the value is reported as zero.
+		let info = UsageInfo::default();
+		assert!(info.to_metrics().iter().all(|(_, value)| *value == 0.0));
+	}
+}
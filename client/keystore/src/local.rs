@@ -317,6 +317,10 @@ impl SyncCryptoStore for LocalKeystore {
 		self.0.write().insert_unknown(key_type, suri, public).map_err(|_| ())
 	}
 
+	fn is_writable(&self) -> bool {
+		self.0.read().is_writable()
+	}
+
 	fn has_keys(&self, public_keys: &[(Vec<u8>, KeyTypeId)]) -> bool {
 		public_keys
 			.iter()
@@ -387,6 +391,18 @@ impl KeystoreInner {
 		Ok(Self { path: Some(path), additional: HashMap::new(), password })
 	}
 
+	/// Returns whether keys can be written to the store, without writing anything.
+	///
+	/// An in-memory store is always writable, a file system store unless its directory is
+	/// read-only.
+	fn is_writable(&self) -> bool {
+		match &self.path {
+			Some(path) =>
+				fs::metadata(path).map(|meta| !meta.permissions().readonly()).unwrap_or(false),
+			None => true,
+		}
+	}
+
 	/// Get the password for this store.
 	fn password(&self) -> Option<&str> {
 		self.password.as_ref().map(|p| p.expose_secret()).map(|p| p.as_str())
@@ -784,4 +800,22 @@ mod tests {
 
 		assert_eq!(0o100600, permissions.mode());
 	}
+
+	#[test]
+	fn is_writable_reflects_directory_permissions() {
+		assert!(SyncCryptoStore::is_writable(&LocalKeystore::in_memory()));
+
+		let temp_dir = TempDir::new().unwrap();
+		let store = LocalKeystore::open(temp_dir.path(), None).unwrap();
+		assert!(SyncCryptoStore::is_writable(&store));
+
+		let original = fs::metadata(temp_dir.path()).unwrap().permissions();
+		let mut read_only = original.clone();
+		read_only.set_readonly(true);
+		fs::set_permissions(temp_dir.path(), read_only).unwrap();
+		assert!(!SyncCryptoStore::is_writable(&store));
+
+		// Let the temporary directory be cleaned up.
+		fs::set_permissions(temp_dir.path(), original).unwrap();
+	}
 }
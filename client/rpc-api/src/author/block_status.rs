@@ -0,0 +1,35 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Block status types for author RPC module.
+
+use serde::{Deserialize, Serialize};
+
+/// What the node knows about a block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockStatusInfo {
+	/// Whether the block is known to the node.
+	pub known: bool,
+	/// The number of the block, if it is known.
+	pub number: Option<u64>,
+	/// Whether the block is part of the best chain, rather than a fork.
+	pub canonical: bool,
+	/// Whether the block is finalized, i.e. canonical and not above the finalized head.
+	pub finalized: bool,
+}
@@ -0,0 +1,41 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Extrinsic mortality helpers for author RPC module.
+
+use serde::{Deserialize, Serialize};
+
+/// The mortality of a signed extrinsic.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EraInfo {
+	/// The extrinsic is valid forever.
+	Immortal,
+	/// The extrinsic is only valid for a limited number of blocks.
+	#[serde(rename_all = "camelCase")]
+	Mortal {
+		/// The number of blocks the extrinsic is valid for.
+		period: u64,
+		/// The phase in the period that the extrinsic's lifetime begins.
+		phase: u64,
+		/// The block the extrinsic's lifetime begins at, as of the best block.
+		birth: u64,
+		/// The first block the extrinsic is no longer valid at, as of the best block.
+		death: u64,
+	},
+}
@@ -45,12 +45,70 @@ pub enum Error {
 	/// Key type ID has an unknown format.
 	#[error("Invalid key type ID format (should be of length four)")]
 	BadKeyType,
+	/// Crypto scheme is not known.
+	#[error("Invalid crypto scheme (should be one of ed25519, sr25519 or ecdsa)")]
+	BadCryptoScheme,
+	/// The secret URI can't be turned into a key pair.
+	#[error("Invalid seed phrase, derivation path or password")]
+	BadSecretUri,
+	/// The derivation path is not made of `/soft` and `//hard` junctions.
+	#[error("Invalid derivation path (should start with `/` and not contain `///`)")]
+	InvalidDerivationPath,
 	/// Some random issue with the key store. Shouldn't happen.
 	#[error("The key store is unavailable")]
 	KeyStoreUnavailable,
 	/// Invalid session keys encoding.
 	#[error("Session keys are not encoded correctly")]
 	InvalidSessionKeys,
+	/// The runtime does not provide the session keys API.
+	#[error("The runtime does not support session keys")]
+	SessionKeysApiUnavailable,
+	/// The node does not expose the runtime APIs the call relies on.
+	#[error("The node does not expose the runtime APIs required by this call")]
+	RuntimeApisUnavailable,
+	/// The sender cannot pay for the transaction.
+	#[error("Inability to pay some fees (e.g. account balance too low)")]
+	Payment,
+	/// The extrinsic is not signed.
+	#[error("Extrinsic is not signed")]
+	UnsignedExtrinsic,
+	/// The call of the extrinsic cannot be extracted.
+	#[error("Extrinsic does not expose its call")]
+	CallUnavailable,
+	/// The signed extensions of the extrinsic cannot be extracted.
+	#[error("Extrinsic does not expose its signed extensions")]
+	ExtensionsUnavailable,
+	/// The best block is not the one the extrinsic was meant to be submitted at.
+	#[error("Best block is {}, expected {}", .actual, .expected)]
+	BestBlockMismatch {
+		/// The expected best block hash.
+		expected: String,
+		/// The actual best block hash.
+		actual: String,
+	},
+	/// The transaction left the pool before reaching the awaited state.
+	#[error("Transaction was {} before reaching the awaited state", .0)]
+	TransactionLeftPool(&'static str),
+	/// The transaction did not reach the awaited state in time.
+	#[error("Transaction did not reach the awaited state within {} blocks", .0)]
+	WaitTimeout(u32),
+	/// The transaction pool stopped watching for the finalization of the including block.
+	#[error("Transaction was included but the pool gave up waiting for its block to be finalized")]
+	FinalityTimeout,
+	/// The requested timeout is above what the node allows.
+	#[error("Timeout of {} blocks exceeds the maximum of {} blocks", .requested, .max)]
+	TimeoutTooLarge {
+		/// The requested number of blocks.
+		requested: u32,
+		/// The maximum number of blocks.
+		max: u32,
+	},
+	/// Extrinsics are submitted faster than the node allows.
+	#[error("Too many extrinsic submissions, retry after {} ms", .retry_after_ms)]
+	RateLimited {
+		/// Milliseconds until the next submission is accepted.
+		retry_after_ms: u64,
+	},
 	/// Call to an unsafe RPC was denied.
 	#[error(transparent)]
 	UnsafeRpcCalled(#[from] crate::policy::UnsafeRpcError),
@@ -62,6 +120,12 @@ const BASE_ERROR: i32 = 1000;
 const BAD_FORMAT: i32 = BASE_ERROR + 1;
 /// Error during transaction verification in runtime.
 const VERIFICATION_ERROR: i32 = BASE_ERROR + 2;
+/// The sender cannot pay for the transaction.
+const PAYMENT_ERROR: i32 = BASE_ERROR + 3;
+/// The best block is not the expected one.
+const BEST_BLOCK_MISMATCH: i32 = BASE_ERROR + 4;
+/// Extrinsics are submitted too fast.
+const RATE_LIMITED: i32 = BASE_ERROR + 5;
 
 /// Pool rejected the transaction as invalid
 const POOL_INVALID_TX: i32 = BASE_ERROR + 10;
@@ -102,6 +166,21 @@ impl From<Error> for JsonRpseeError {
 				format!("Verification Error: {}", e),
 				Some(format!("{:?}", e)),
 			)),
+			Error::Payment => CallError::Custom(ErrorObject::owned(
+				PAYMENT_ERROR,
+				"Payment Error",
+				Some("Inability to pay some fees (e.g. account balance too low)"),
+			)),
+			Error::BestBlockMismatch { expected, actual } => CallError::Custom(ErrorObject::owned(
+				BEST_BLOCK_MISMATCH,
+				"Best Block Mismatch",
+				Some(format!("Best block is {}, expected {}", actual, expected)),
+			)),
+			Error::RateLimited { retry_after_ms } => CallError::Custom(ErrorObject::owned(
+				RATE_LIMITED,
+				"Rate Limited",
+				Some(retry_after_ms),
+			)),
 			Error::Pool(PoolError::InvalidTransaction(InvalidTransaction::Custom(e))) => {
 				CallError::Custom(ErrorObject::owned(
 					POOL_INVALID_TX,
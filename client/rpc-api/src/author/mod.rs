@@ -19,11 +19,17 @@
 //! Substrate block-author/full-node API.
 
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use sc_transaction_pool_api::TransactionStatus;
+use sc_transaction_pool_api::{PoolLimits, PoolStatus, TransactionStatus};
 use sp_core::Bytes;
 
+pub mod block_status;
+pub mod dry_run;
+pub mod era;
 pub mod error;
+pub mod finalized;
 pub mod hash;
+pub mod inclusion;
+pub mod receipt;
 
 /// Substrate authoring RPC API
 #[rpc(client, server)]
@@ -32,10 +38,123 @@ pub trait AuthorApi<Hash, BlockHash> {
 	#[method(name = "author_submitExtrinsic")]
 	async fn submit_extrinsic(&self, extrinsic: Bytes) -> RpcResult<Hash>;
 
+	/// Submit hex-encoded extrinsic for inclusion in block, tolerating retries.
+	///
+	/// Like `author_submitExtrinsic`, but if the extrinsic is already in the pool its hash is
+	/// returned instead of an "Already imported" error. Other failures are reported as usual.
+	#[method(name = "author_submitExtrinsicIdempotent")]
+	async fn submit_extrinsic_idempotent(&self, extrinsic: Bytes) -> RpcResult<Hash>;
+
+	/// Submit hex-encoded extrinsic for inclusion in block, attaching a `label` to it.
+	///
+	/// The label is node-local metadata that can be queried with `author_labelOf` until the
+	/// extrinsic leaves the pool.
+	#[method(name = "author_submitExtrinsicLabeled")]
+	async fn submit_extrinsic_labeled(&self, extrinsic: Bytes, label: String) -> RpcResult<Hash>;
+
+	/// Returns the label attached to the extrinsic with the given hash, if any.
+	#[method(name = "author_labelOf")]
+	fn label_of(&self, hash: Hash) -> RpcResult<Option<String>>;
+
+	/// Submit hex-encoded extrinsic for inclusion in block, failing early if it cannot pay.
+	///
+	/// Like `author_submitExtrinsic`, but if validation finds that the sender cannot pay for the
+	/// extrinsic, a dedicated payment error is returned instead of the generic invalid
+	/// transaction error. The extrinsic is only submitted if it is valid.
+	///
+	/// Only served by nodes exposing the `TaggedTransactionQueue` runtime API to the author RPC.
+	#[method(name = "author_submitExtrinsicChecked")]
+	async fn submit_extrinsic_checked(&self, extrinsic: Bytes) -> RpcResult<Hash>;
+
+	/// Submit hex-encoded extrinsic for inclusion in block, only if `expected_best` is the best
+	/// block.
+	///
+	/// Guards mortality-sensitive submissions against an unexpected fork: if the best block
+	/// differs, nothing is submitted and a best block mismatch error is returned.
+	#[method(name = "author_submitExtrinsicIfBest")]
+	async fn submit_extrinsic_if_best(
+		&self,
+		extrinsic: Bytes,
+		expected_best: BlockHash,
+	) -> RpcResult<Hash>;
+
+	/// Submit hex-encoded extrinsic and wait until it is included in a finalized block.
+	///
+	/// Returns the hash of the finalized block that includes the extrinsic. Fails if the
+	/// extrinsic is dropped, invalid or usurped, or if it is not finalized before
+	/// `timeout_blocks` new best blocks have been imported. Larger `timeout_blocks` than the node
+	/// allows are rejected.
+	#[method(name = "author_submitExtrinsicAndWait")]
+	async fn submit_extrinsic_and_wait(
+		&self,
+		extrinsic: Bytes,
+		timeout_blocks: u32,
+	) -> RpcResult<BlockHash>;
+
+	/// Submit hex-encoded extrinsic and wait until it is included in a block.
+	///
+	/// Returns the hash of the first block that includes the extrinsic, and whether it was
+	/// reported finalized. A block that is not finalized could still be retracted by a reorg;
+	/// use `author_submitExtrinsicAndWait` to wait for finality. Fails if the extrinsic is
+	/// dropped, invalid or usurped, or if it is not included before `timeout_blocks` new best
+	/// blocks have been imported. Larger `timeout_blocks` than the node allows are rejected.
+	#[method(name = "author_submitExtrinsicInBlock")]
+	async fn submit_extrinsic_in_block(
+		&self,
+		extrinsic: Bytes,
+		timeout_blocks: u32,
+	) -> RpcResult<inclusion::Inclusion<BlockHash>>;
+
+	/// Submit hex-encoded extrinsic as a local transaction, with `priority` in place of the
+	/// priority computed by the runtime.
+	///
+	/// Meant for the node's own transactions. The override holds until the extrinsic is
+	/// revalidated by the pool.
+	#[method(name = "author_submitExtrinsicWithPriority")]
+	async fn submit_extrinsic_with_priority(
+		&self,
+		extrinsic: Bytes,
+		priority: u64,
+	) -> RpcResult<Hash>;
+
+	/// Submit hex-encoded extrinsic for inclusion in block, and return a receipt to track it by.
+	///
+	/// The receipt holds the extrinsic hash, the best block it was validated against, the
+	/// priority and longevity computed by validation, and the genesis hash.
+	///
+	/// Only served by nodes exposing the `TaggedTransactionQueue` runtime API to the author RPC.
+	#[method(name = "author_submitExtrinsicReceipt")]
+	async fn submit_extrinsic_receipt(
+		&self,
+		extrinsic: Bytes,
+	) -> RpcResult<receipt::SubmitReceipt<Hash, BlockHash>>;
+
+	/// Revalidate all ready extrinsics in the pool against the current best block.
+	///
+	/// Returns the number of extrinsics that were dropped as invalid.
+	#[method(name = "author_revalidatePool")]
+	async fn revalidate_pool(&self) -> RpcResult<u64>;
+
 	/// Insert a key into the keystore.
 	#[method(name = "author_insertKey")]
 	fn insert_key(&self, key_type: String, suri: String, public: Bytes) -> RpcResult<()>;
 
+	/// Insert a key derived from `seed_phrase` into the keystore, and return its public key.
+	///
+	/// The secret URI is assembled from the seed phrase, the derivation `path` (e.g.
+	/// `//stash/0`) and the `password`. `scheme` is one of `ed25519`, `sr25519` or `ecdsa`. A
+	/// `path` that does not start with `/`, or that contains the `///` password separator, is
+	/// rejected.
+	#[method(name = "author_insertKeyDerived")]
+	fn insert_key_derived(
+		&self,
+		key_type: String,
+		scheme: String,
+		seed_phrase: String,
+		path: Option<String>,
+		password: Option<String>,
+	) -> RpcResult<Bytes>;
+
 	/// Generate new session keys and returns the corresponding public keys.
 	#[method(name = "author_rotateKeys")]
 	fn rotate_keys(&self) -> RpcResult<Bytes>;
@@ -48,16 +167,119 @@ pub trait AuthorApi<Hash, BlockHash> {
 	#[method(name = "author_hasSessionKeys")]
 	fn has_session_keys(&self, session_keys: Bytes) -> RpcResult<bool>;
 
+	/// Returns the key types the runtime's session keys are made of, in order.
+	///
+	/// Each of them needs a key in the keystore, e.g. through `author_insertKey`.
+	#[method(name = "author_requiredKeyTypes")]
+	fn required_key_types(&self) -> RpcResult<Vec<String>>;
+
+	/// Checks if the keystore holds the session keys of one of the current validators.
+	///
+	/// Only served by nodes exposing the `ValidatorSessionKeys` runtime API to the author RPC.
+	#[method(name = "author_isAuthoring")]
+	fn is_authoring(&self) -> RpcResult<bool>;
+
 	/// Checks if the keystore has private keys for the given public key and key type.
 	///
 	/// Returns `true` if a private key could be found.
 	#[method(name = "author_hasKey")]
 	fn has_key(&self, public_key: Bytes, key_type: String) -> RpcResult<bool>;
 
+	/// Checks if the keystore would currently accept new keys, without inserting any.
+	///
+	/// Returns `false` e.g. if the keystore was opened read-only.
+	#[method(name = "author_keystoreWritable")]
+	fn keystore_writable(&self) -> RpcResult<bool>;
+
+	/// Decode the given signed extrinsic and return its mortality.
+	///
+	/// For mortal extrinsics, the birth and death blocks are computed as of the best block.
+	#[method(name = "author_extrinsicEra")]
+	fn extrinsic_era(&self, extrinsic: Bytes) -> RpcResult<era::EraInfo>;
+
+	/// Dry run the given extrinsic on top of the state of block `at`, or of the best block.
+	///
+	/// Returns the outcome of applying the extrinsic and the events it deposited. All state
+	/// changes are discarded afterwards. This executes the extrinsic and is a heavy call.
+	///
+	/// Only served by nodes exposing the `DryRunApi` runtime API to the author RPC.
+	#[method(name = "author_dryRunExtrinsic")]
+	fn dry_run_extrinsic(
+		&self,
+		extrinsic: Bytes,
+		at: Option<BlockHash>,
+	) -> RpcResult<dry_run::DryRunResult>;
+
+	/// Returns whether the block with the given hash is known, its number, and whether it is
+	/// canonical and finalized.
+	///
+	/// Meant to check the block an extrinsic was included in.
+	#[method(name = "author_blockStatus")]
+	fn block_status(&self, hash: BlockHash) -> RpcResult<block_status::BlockStatusInfo>;
+
+	/// Returns the hash of the block including the given extrinsic, if it is one of the last
+	/// `depth` finalized blocks.
+	///
+	/// Lets clients check an extrinsic has not landed before resubmitting it. `depth` is capped
+	/// by the node.
+	#[method(name = "author_isInRecentBlock")]
+	fn is_in_recent_block(&self, extrinsic: Bytes, depth: u32) -> RpcResult<Option<BlockHash>>;
+
+	/// Decode the given extrinsic and return the hash the transaction pool identifies it by,
+	/// without submitting it.
+	#[method(name = "author_extrinsicHash")]
+	fn extrinsic_hash(&self, extrinsic: Bytes) -> RpcResult<Hash>;
+
+	/// Decode the given extrinsic and return its SCALE-encoded call, without any signature or
+	/// signed extension data.
+	#[method(name = "author_extractCall")]
+	fn extract_call(&self, extrinsic: Bytes) -> RpcResult<Bytes>;
+
+	/// Decode the given signed extrinsic and return the identifier and SCALE-encoded explicit
+	/// data of each of its signed extensions, in order.
+	#[method(name = "author_extrinsicExtensions")]
+	fn extrinsic_extensions(&self, extrinsic: Bytes) -> RpcResult<Vec<(String, Bytes)>>;
+
+	/// Returns the hash and watch id of the extrinsic tracked by each open
+	/// `author_submitAndWatchExtrinsic` subscription.
+	///
+	/// Watch ids are assigned by this node in the order the watches were opened, and are not
+	/// the subscription ids.
+	#[method(name = "author_activeWatches")]
+	fn active_watches(&self) -> RpcResult<Vec<(Hash, String)>>;
+
 	/// Returns all pending extrinsics, potentially grouped by sender.
 	#[method(name = "author_pendingExtrinsics")]
 	fn pending_extrinsics(&self) -> RpcResult<Vec<Bytes>>;
 
+	/// Returns the limits the transaction pool was configured with.
+	#[method(name = "author_poolLimits")]
+	fn pool_limits(&self) -> RpcResult<PoolLimits>;
+
+	/// Returns the lowest priority of the ready transactions if the ready queue is full, or
+	/// `None` otherwise.
+	///
+	/// A new transaction must have a higher priority to make it into a full pool.
+	#[method(name = "author_poolMinPriority")]
+	fn pool_min_priority(&self) -> RpcResult<Option<u64>>;
+
+	/// Returns the hashes of up to `limit` extrinsics submitted through this node that have
+	/// since been finalized, most recent first.
+	#[method(name = "author_recentlyFinalized")]
+	fn recently_finalized(&self, limit: u32) -> RpcResult<Vec<Hash>>;
+
+	/// Returns the `(spec_version, transaction_version)` of the runtime at the best block.
+	///
+	/// Lets clients check they are compatible with the node before signing an extrinsic.
+	#[method(name = "author_runtimeSpec")]
+	fn runtime_spec(&self) -> RpcResult<(u32, u32)>;
+
+	/// Returns the hash of the genesis block.
+	///
+	/// Lets clients construct mortal extrinsics and check they are connected to the right chain.
+	#[method(name = "author_genesisHash")]
+	fn genesis_hash(&self) -> RpcResult<BlockHash>;
+
 	/// Remove given extrinsic from the pool and temporarily ban it to prevent reimporting.
 	#[method(name = "author_removeExtrinsic")]
 	fn remove_extrinsic(
@@ -75,4 +297,39 @@ pub trait AuthorApi<Hash, BlockHash> {
 		item = TransactionStatus<Hash, BlockHash>,
 	)]
 	fn watch_extrinsic(&self, bytes: Bytes);
+
+	/// Submit several extrinsics and watch them all under a single subscription.
+	///
+	/// Each notification is tagged with the hash of the extrinsic it is about. The subscription
+	/// ends once all extrinsics reached a final state. If any extrinsic fails to be imported,
+	/// the subscription is rejected; the ones submitted before it stay in the pool.
+	#[subscription(
+		name = "author_submitAndWatchExtrinsicBatch" => "author_extrinsicBatchUpdate",
+		unsubscribe = "author_unwatchExtrinsicBatch",
+		item = (Hash, TransactionStatus<Hash, BlockHash>),
+	)]
+	fn submit_and_watch_batch(&self, extrinsics: Vec<Bytes>);
+
+	/// Subscribe to the finalized blocks that include extrinsics submitted through this node.
+	///
+	/// A notification is sent for each such block, carrying the hashes of the extrinsics it
+	/// includes. Only the most recent submissions are remembered by the node.
+	#[subscription(
+		name = "author_subscribeMyFinalized" => "author_myFinalized",
+		unsubscribe = "author_unsubscribeMyFinalized",
+		item = finalized::FinalizedExtrinsics<Hash, BlockHash>,
+	)]
+	fn watch_my_finalized(&self);
+
+	/// Subscribe to the status of the transaction pool.
+	///
+	/// The current status is sent first, then a new one whenever the number of ready or future
+	/// transactions changed. Changes are checked once per imported block, so at most one status is
+	/// sent per block and intermediate states may be skipped.
+	#[subscription(
+		name = "author_subscribePoolStatus" => "author_poolStatus",
+		unsubscribe = "author_unsubscribePoolStatus",
+		item = PoolStatus,
+	)]
+	fn watch_pool_status(&self);
 }
@@ -0,0 +1,37 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Submission receipt types for author RPC module.
+
+use serde::{Deserialize, Serialize};
+
+/// Everything needed to track a submitted extrinsic without a subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitReceipt<Hash, BlockHash> {
+	/// Hash of the extrinsic in the transaction pool.
+	pub hash: Hash,
+	/// Best block the extrinsic was validated against.
+	pub validated_at: BlockHash,
+	/// Priority of the extrinsic, as computed by validation.
+	pub priority: u64,
+	/// Number of blocks the extrinsic stays valid for, as computed by validation.
+	pub longevity: u64,
+	/// Genesis hash of the chain.
+	pub genesis_hash: BlockHash,
+}
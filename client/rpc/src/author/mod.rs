@@ -21,35 +21,55 @@
 #[cfg(test)]
 mod tests;
 
-use std::sync::Arc;
+use std::{
+	collections::{BTreeMap, HashMap, HashSet, VecDeque},
+	hash::Hash,
+	num::NonZeroU32,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+	time::Instant,
+};
 
 use crate::SubscriptionTaskExecutor;
 
 use codec::{Decode, Encode};
-use futures::{FutureExt, TryFutureExt};
+use futures::{channel::oneshot, future, FutureExt, Stream, StreamExt, TryFutureExt};
 use jsonrpsee::{
 	core::{async_trait, Error as JsonRpseeError, RpcResult},
 	types::SubscriptionResult,
 	SubscriptionSink,
 };
+use parking_lot::Mutex;
+use sc_client_api::{BlockBackend, BlockchainEvents};
 use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::{
-	error::IntoPoolError, BlockHash, InPoolTransaction, TransactionFor, TransactionPool,
-	TransactionSource, TxHash,
+	error::{Error as PoolError, IntoPoolError},
+	BlockHash, InPoolTransaction, MaintainedTransactionPool, PoolLimits, TransactionFor,
+	TransactionPool, TransactionSource, TransactionStatus, TxHash,
 };
-use sp_api::ProvideRuntimeApi;
+use sp_api::{Core, ProvideRuntimeApi, RuntimeApiInfo};
+use sp_block_builder::DryRunApi;
 use sp_blockchain::HeaderBackend;
-use sp_core::Bytes;
+use sp_core::{crypto::KeyTypeId, ecdsa, ed25519, sr25519, Bytes, Pair};
 use sp_keystore::{SyncCryptoStore, SyncCryptoStorePtr};
-use sp_runtime::{generic, traits::Block as BlockT};
-use sp_session::SessionKeys;
+use sp_runtime::{
+	generic::{self, Era},
+	traits::{Block as BlockT, Extrinsic, One, SaturatedConversion, Zero},
+	transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError},
+	ApplyExtrinsicResult,
+};
+use sp_session::{SessionKeys, ValidatorSessionKeys};
+use sp_transaction_pool::runtime_api::TaggedTransactionQueue;
+use sp_version::RuntimeVersion;
 
 use self::error::{Error, Result};
 /// Re-export the API for backward compatibility.
 pub use sc_rpc_api::author::*;
 
 /// Authoring API
-pub struct Author<P, Client> {
+pub struct Author<P: TransactionPool, Client> {
 	/// Substrate client
 	client: Arc<Client>,
 	/// Transactions pool
@@ -60,21 +80,326 @@ pub struct Author<P, Client> {
 	deny_unsafe: DenyUnsafe,
 	/// Executor to spawn subscriptions.
 	executor: SubscriptionTaskExecutor,
+	/// Hashes of extrinsics submitted through this node that have been finalized.
+	recently_finalized: Arc<RecentlyFinalized<TxHash<P>>>,
+	/// Labels attached to extrinsics submitted through this node that are still in the pool.
+	labels: Arc<SubmissionLabels<TxHash<P>>>,
+	/// Hashes of the most recent extrinsics submitted through this node.
+	submitted: Arc<SubmittedHashes<TxHash<P>>>,
+	/// Limits the rate of extrinsic submissions.
+	rate_limiter: Arc<RateLimiter>,
+	/// Extrinsics tracked by open watch subscriptions.
+	watches: Arc<ActiveWatches<TxHash<P>>>,
+	/// Runtime APIs beyond [`SessionKeys`], see [`Author::with_runtime_apis`].
+	runtime_apis: Option<Arc<dyn AuthorRuntimeApis<P::Block>>>,
 }
 
-impl<P, Client> Author<P, Client> {
+impl<P: TransactionPool, Client> Author<P, Client> {
 	/// Create new instance of Authoring API.
+	///
+	/// Up to `recently_finalized_capacity` hashes of finalized extrinsics submitted through
+	/// [`AuthorApiServer::submit_extrinsic`] are remembered. Zero disables the tracking.
+	///
+	/// At most `max_submissions_per_second` extrinsics are accepted per second, summed over the
+	/// submission methods, [`AuthorApiServer::watch_extrinsic`] and the extrinsics of each
+	/// [`AuthorApiServer::submit_and_watch_batch`]. `None` disables the limit.
 	pub fn new(
 		client: Arc<Client>,
 		pool: Arc<P>,
 		keystore: SyncCryptoStorePtr,
 		deny_unsafe: DenyUnsafe,
 		executor: SubscriptionTaskExecutor,
+		recently_finalized_capacity: usize,
+		max_submissions_per_second: Option<NonZeroU32>,
 	) -> Self {
-		Author { client, pool, keystore, deny_unsafe, executor }
+		Author {
+			client,
+			pool,
+			keystore,
+			deny_unsafe,
+			executor,
+			recently_finalized: Arc::new(RecentlyFinalized::new(recently_finalized_capacity)),
+			labels: Arc::new(SubmissionLabels::new(SUBMISSION_LABELS_CAPACITY)),
+			submitted: Arc::new(SubmittedHashes::new(SUBMITTED_HASHES_CAPACITY)),
+			rate_limiter: Arc::new(RateLimiter::new(max_submissions_per_second)),
+			watches: Arc::new(ActiveWatches::new()),
+			runtime_apis: None,
+		}
+	}
+}
+
+impl<P, Client> Author<P, Client>
+where
+	P: TransactionPool,
+	Client: ProvideRuntimeApi<P::Block> + Send + Sync + 'static,
+	Client::Api:
+		TaggedTransactionQueue<P::Block> + DryRunApi<P::Block> + ValidatorSessionKeys<P::Block>,
+{
+	/// Enable the methods relying on the [`TaggedTransactionQueue`], [`DryRunApi`] and
+	/// [`ValidatorSessionKeys`] runtime APIs, [`AuthorApiServer::submit_extrinsic_checked`],
+	/// [`AuthorApiServer::submit_extrinsic_receipt`], [`AuthorApiServer::dry_run_extrinsic`]
+	/// and [`AuthorApiServer::is_authoring`].
+	///
+	/// They fail with [`Error::RuntimeApisUnavailable`] otherwise.
+	pub fn with_runtime_apis(self) -> Self {
+		Author { runtime_apis: Some(self.client.clone()), ..self }
+	}
+}
+
+/// The runtime APIs used by [`Author`] on top of [`SessionKeys`].
+///
+/// Keeps their bounds off the [`AuthorApiServer`] implementation, so that nodes whose
+/// runtime lacks them can still serve the other methods.
+trait AuthorRuntimeApis<Block: BlockT>: Send + Sync {
+	/// Validate `xt` against the state of `at`.
+	fn validate_transaction(
+		&self,
+		at: Block::Hash,
+		xt: Block::Extrinsic,
+	) -> Result<TransactionValidity>;
+
+	/// Apply `xt` on top of the state of `at` and discard the changes.
+	///
+	/// Returns the inclusion outcome and the encoded event records deposited by `xt`.
+	fn dry_run_extrinsic(
+		&self,
+		at: Block::Hash,
+		xt: Block::Extrinsic,
+	) -> Result<(ApplyExtrinsicResult, Vec<u8>)>;
+
+	/// The session keys of each of the validators as of `at`.
+	fn validator_session_keys(&self, at: Block::Hash) -> Result<Vec<Vec<(Vec<u8>, KeyTypeId)>>>;
+}
+
+impl<Block, Client> AuthorRuntimeApis<Block> for Client
+where
+	Block: BlockT,
+	Client: ProvideRuntimeApi<Block> + Send + Sync,
+	Client::Api: TaggedTransactionQueue<Block> + DryRunApi<Block> + ValidatorSessionKeys<Block>,
+{
+	fn validate_transaction(
+		&self,
+		at: Block::Hash,
+		xt: Block::Extrinsic,
+	) -> Result<TransactionValidity> {
+		self.runtime_api()
+			.validate_transaction(&generic::BlockId::hash(at), TX_SOURCE, xt, at)
+			.map_err(|e| Error::Client(Box::new(e)))
+	}
+
+	fn dry_run_extrinsic(
+		&self,
+		at: Block::Hash,
+		xt: Block::Extrinsic,
+	) -> Result<(ApplyExtrinsicResult, Vec<u8>)> {
+		// The changes only live in the overlay of the runtime API instance, dropped afterwards.
+		self.runtime_api()
+			.dry_run_extrinsic(&generic::BlockId::hash(at), xt, at)
+			.map_err(|e| Error::Client(Box::new(e)))
+	}
+
+	fn validator_session_keys(&self, at: Block::Hash) -> Result<Vec<Vec<(Vec<u8>, KeyTypeId)>>> {
+		self.runtime_api()
+			.validator_session_keys(&generic::BlockId::hash(at))
+			.map_err(|e| Error::Client(Box::new(e)))
+	}
+}
+
+/// Bounded buffer of the most recently finalized transaction hashes.
+struct RecentlyFinalized<Hash> {
+	capacity: usize,
+	hashes: Mutex<VecDeque<Hash>>,
+	/// Whether the task filling the buffer has been spawned.
+	tracking: AtomicBool,
+}
+
+impl<Hash: Clone> RecentlyFinalized<Hash> {
+	fn new(capacity: usize) -> Self {
+		Self {
+			capacity,
+			hashes: Mutex::new(VecDeque::with_capacity(capacity)),
+			tracking: AtomicBool::new(false),
+		}
+	}
+
+	/// Returns `true` the first time it is called on an enabled buffer, when the task filling
+	/// it is to be spawned.
+	fn start_tracking(&self) -> bool {
+		self.capacity != 0 && !self.tracking.swap(true, Ordering::Relaxed)
+	}
+
+	fn push(&self, hash: Hash) {
+		let mut hashes = self.hashes.lock();
+		if hashes.len() >= self.capacity {
+			hashes.pop_back();
+		}
+		hashes.push_front(hash);
+	}
+
+	fn latest(&self, limit: usize) -> Vec<Hash> {
+		self.hashes.lock().iter().take(limit).cloned().collect()
+	}
+}
+
+/// Bounded map of labels attached to submitted transactions.
+///
+/// When full, the oldest label is evicted to make room for a new one.
+struct SubmissionLabels<H> {
+	capacity: usize,
+	inner: Mutex<(HashMap<H, String>, VecDeque<H>)>,
+}
+
+impl<H: Clone + Eq + Hash> SubmissionLabels<H> {
+	fn new(capacity: usize) -> Self {
+		Self { capacity, inner: Mutex::new(Default::default()) }
+	}
+
+	fn insert(&self, hash: H, label: String) {
+		let (labels, order) = &mut *self.inner.lock();
+		if labels.insert(hash.clone(), label).is_none() {
+			order.push_back(hash);
+		}
+		while labels.len() > self.capacity {
+			match order.pop_front() {
+				Some(oldest) => labels.remove(&oldest),
+				None => break,
+			};
+		}
+	}
+
+	fn remove(&self, hash: &H) {
+		let (labels, order) = &mut *self.inner.lock();
+		if labels.remove(hash).is_some() {
+			order.retain(|h| h != hash);
+		}
+	}
+
+	fn get(&self, hash: &H) -> Option<String> {
+		self.inner.lock().0.get(hash).cloned()
+	}
+}
+
+/// Maximum number of labels kept by [`Author`].
+const SUBMISSION_LABELS_CAPACITY: usize = 4096;
+
+/// Bounded set of the hashes of submitted transactions.
+///
+/// When full, the oldest hash is evicted to make room for a new one.
+struct SubmittedHashes<H> {
+	capacity: usize,
+	inner: Mutex<(HashSet<H>, VecDeque<H>)>,
+}
+
+impl<H: Clone + Eq + Hash> SubmittedHashes<H> {
+	fn new(capacity: usize) -> Self {
+		Self { capacity, inner: Mutex::new(Default::default()) }
+	}
+
+	fn insert(&self, hash: H) {
+		let (hashes, order) = &mut *self.inner.lock();
+		if hashes.insert(hash.clone()) {
+			order.push_back(hash);
+		}
+		while hashes.len() > self.capacity {
+			match order.pop_front() {
+				Some(oldest) => hashes.remove(&oldest),
+				None => break,
+			};
+		}
+	}
+
+	fn contains(&self, hash: &H) -> bool {
+		self.inner.lock().0.contains(hash)
+	}
+}
+
+/// The extrinsics tracked by open watch subscriptions, by watch id.
+struct ActiveWatches<H> {
+	/// The id of the next watch, and the open watches.
+	inner: Mutex<(u64, BTreeMap<u64, H>)>,
+}
+
+impl<H: Clone> ActiveWatches<H> {
+	fn new() -> Self {
+		Self { inner: Mutex::new((0, BTreeMap::new())) }
+	}
+
+	/// Register a watch of `hash`, returning its id.
+	fn insert(&self, hash: H) -> u64 {
+		let (next_id, watches) = &mut *self.inner.lock();
+		let id = *next_id;
+		*next_id += 1;
+		watches.insert(id, hash);
+		id
+	}
+
+	fn remove(&self, id: u64) {
+		self.inner.lock().1.remove(&id);
+	}
+
+	fn list(&self) -> Vec<(H, String)> {
+		self.inner
+			.lock()
+			.1
+			.iter()
+			.map(|(id, hash)| (hash.clone(), id.to_string()))
+			.collect()
+	}
+}
+
+/// Token bucket limiting the rate of submissions, refilled as time passes.
+///
+/// jsonrpsee tells neither method handlers nor subscription sinks which connection a request
+/// comes from, so every submission path takes its tokens from this one bucket.
+struct RateLimiter {
+	/// Tokens added per second, which is also the bucket capacity. `None` disables the limit.
+	rate: Option<NonZeroU32>,
+	/// The available tokens as of the given instant.
+	bucket: Mutex<(f64, Instant)>,
+}
+
+impl RateLimiter {
+	/// A limiter whose bucket starts full.
+	fn new(rate: Option<NonZeroU32>) -> Self {
+		let tokens = rate.map_or(0.0, |rate| rate.get() as f64);
+		Self { rate, bucket: Mutex::new((tokens, Instant::now())) }
+	}
+
+	/// Take `count` tokens from the bucket, or none and fail with the error telling when enough
+	/// are available.
+	///
+	/// Requests for more tokens than the bucket holds always fail.
+	fn acquire(&self, count: u32) -> Result<()> {
+		let rate = match self.rate {
+			Some(rate) => rate.get() as f64,
+			None => return Ok(()),
+		};
+
+		let now = Instant::now();
+		let (tokens, refilled_at) = &mut *self.bucket.lock();
+		*tokens = (*tokens + now.duration_since(*refilled_at).as_secs_f64() * rate).min(rate);
+		*refilled_at = now;
+
+		let count = count as f64;
+		if *tokens >= count {
+			*tokens -= count;
+			Ok(())
+		} else {
+			let retry_after_ms = ((count - *tokens) * 1000.0 / rate).ceil() as u64;
+			Err(Error::RateLimited { retry_after_ms })
+		}
 	}
 }
 
+/// Upper bound on the encoded length of the runtime's session keys.
+const SESSION_KEYS_MAX_ENCODED_LEN: usize = 1024;
+
+/// Maximum number of submitted transaction hashes kept by [`Author`].
+const SUBMITTED_HASHES_CAPACITY: usize = 4096;
+
+/// Maximum number of finalized blocks scanned by `author_isInRecentBlock`.
+const RECENT_BLOCKS_MAX_DEPTH: u32 = 256;
+
 /// Currently we treat all RPC transactions as externals.
 ///
 /// Possibly in the future we could allow opt-in for special treatment
@@ -82,11 +407,23 @@ impl<P, Client> Author<P, Client> {
 /// some unique transactions via RPC and have them included in the pool.
 const TX_SOURCE: TransactionSource = TransactionSource::External;
 
+/// Maximum `timeout_blocks` accepted by the methods waiting for the inclusion of an extrinsic.
+pub const MAX_WAIT_TIMEOUT_BLOCKS: u32 = 600;
+
+/// Default number of finalized extrinsic hashes remembered by [`Author`].
+pub const RECENTLY_FINALIZED_CAPACITY: usize = 1024;
+
 #[async_trait]
 impl<P, Client> AuthorApiServer<TxHash<P>, BlockHash<P>> for Author<P, Client>
 where
-	P: TransactionPool + Sync + Send + 'static,
-	Client: HeaderBackend<P::Block> + ProvideRuntimeApi<P::Block> + Send + Sync + 'static,
+	P: MaintainedTransactionPool + Sync + Send + 'static,
+	Client: HeaderBackend<P::Block>
+		+ ProvideRuntimeApi<P::Block>
+		+ BlockchainEvents<P::Block>
+		+ BlockBackend<P::Block>
+		+ Send
+		+ Sync
+		+ 'static,
 	Client::Api: SessionKeys<P::Block>,
 	P::Hash: Unpin,
 	<P::Block as BlockT>::Hash: Unpin,
@@ -96,16 +433,136 @@ where
 			Ok(xt) => xt,
 			Err(err) => return Err(Error::Client(Box::new(err)).into()),
 		};
+		self.submit(xt, None).await.map_err(Into::into)
+	}
+
+	async fn submit_extrinsic_idempotent(&self, ext: Bytes) -> RpcResult<TxHash<P>> {
+		let xt = match Decode::decode(&mut &ext[..]) {
+			Ok(xt) => xt,
+			Err(err) => return Err(Error::Client(Box::new(err)).into()),
+		};
+		let hash = self.pool.hash_of(&xt);
+		match self.submit(xt, None).await {
+			Err(Error::Pool(PoolError::AlreadyImported(_))) => Ok(hash),
+			result => result.map_err(Into::into),
+		}
+	}
+
+	async fn submit_extrinsic_labeled(&self, ext: Bytes, label: String) -> RpcResult<TxHash<P>> {
+		let xt = match Decode::decode(&mut &ext[..]) {
+			Ok(xt) => xt,
+			Err(err) => return Err(Error::Client(Box::new(err)).into()),
+		};
+		self.submit(xt, Some(label)).await.map_err(Into::into)
+	}
+
+	fn label_of(&self, hash: TxHash<P>) -> RpcResult<Option<String>> {
+		Ok(self.labels.get(&hash))
+	}
+
+	async fn submit_extrinsic_checked(&self, ext: Bytes) -> RpcResult<TxHash<P>> {
+		let runtime_apis = self.runtime_apis.as_ref().ok_or(Error::RuntimeApisUnavailable)?;
+		let xt: TransactionFor<P> = match Decode::decode(&mut &ext[..]) {
+			Ok(xt) => xt,
+			Err(err) => return Err(Error::Client(Box::new(err)).into()),
+		};
+		let best_block_hash = self.client.info().best_hash;
+		runtime_apis
+			.validate_transaction(best_block_hash, xt.clone())?
+			.map_err(checked_validity_error)?;
+
+		self.submit(xt, None).await.map_err(Into::into)
+	}
+
+	async fn submit_extrinsic_if_best(
+		&self,
+		ext: Bytes,
+		expected_best: BlockHash<P>,
+	) -> RpcResult<TxHash<P>> {
+		let xt = match Decode::decode(&mut &ext[..]) {
+			Ok(xt) => xt,
+			Err(err) => return Err(Error::Client(Box::new(err)).into()),
+		};
+		let best_block_hash = self.client.info().best_hash;
+		if best_block_hash != expected_best {
+			return Err(Error::BestBlockMismatch {
+				expected: format!("{:?}", expected_best),
+				actual: format!("{:?}", best_block_hash),
+			}
+			.into())
+		}
+		self.submit(xt, None).await.map_err(Into::into)
+	}
+
+	async fn submit_extrinsic_and_wait(
+		&self,
+		ext: Bytes,
+		timeout_blocks: u32,
+	) -> RpcResult<BlockHash<P>> {
+		let inclusion = self.submit_and_wait(ext, timeout_blocks, true).await?;
+		Ok(inclusion.block)
+	}
+
+	async fn submit_extrinsic_in_block(
+		&self,
+		ext: Bytes,
+		timeout_blocks: u32,
+	) -> RpcResult<inclusion::Inclusion<BlockHash<P>>> {
+		self.submit_and_wait(ext, timeout_blocks, false).await.map_err(Into::into)
+	}
+
+	async fn submit_extrinsic_with_priority(
+		&self,
+		ext: Bytes,
+		priority: u64,
+	) -> RpcResult<TxHash<P>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let xt = match Decode::decode(&mut &ext[..]) {
+			Ok(xt) => xt,
+			Err(err) => return Err(Error::Client(Box::new(err)).into()),
+		};
+		self.rate_limiter.acquire(1)?;
 		let best_block_hash = self.client.info().best_hash;
-		self.pool
-			.submit_one(&generic::BlockId::hash(best_block_hash), TX_SOURCE, xt)
+		let hash = self
+			.pool
+			.submit_local_with_priority(&generic::BlockId::hash(best_block_hash), xt, priority)
 			.await
 			.map_err(|e| {
 				e.into_pool_error()
 					.map(|e| Error::Pool(e))
 					.unwrap_or_else(|e| Error::Verification(Box::new(e)))
-					.into()
-			})
+			})?;
+		self.submitted.insert(hash.clone());
+		Ok(hash)
+	}
+
+	async fn submit_extrinsic_receipt(
+		&self,
+		ext: Bytes,
+	) -> RpcResult<receipt::SubmitReceipt<TxHash<P>, BlockHash<P>>> {
+		let runtime_apis = self.runtime_apis.as_ref().ok_or(Error::RuntimeApisUnavailable)?;
+		let xt = TransactionFor::<P>::decode(&mut &ext[..]).map_err(Error::BadFormat)?;
+		let info = self.client.info();
+		let validity = runtime_apis
+			.validate_transaction(info.best_hash, xt.clone())?
+			.map_err(validity_error)?;
+
+		let hash = self.submit(xt, None).await?;
+		Ok(receipt::SubmitReceipt {
+			hash,
+			validated_at: info.best_hash,
+			priority: validity.priority,
+			longevity: validity.longevity,
+			genesis_hash: info.genesis_hash,
+		})
+	}
+
+	async fn revalidate_pool(&self) -> RpcResult<u64> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let best_block_hash = self.client.info().best_hash;
+		Ok(self.pool.revalidate(best_block_hash).await as u64)
 	}
 
 	fn insert_key(&self, key_type: String, suri: String, public: Bytes) -> RpcResult<()> {
@@ -117,10 +574,46 @@ where
 		Ok(())
 	}
 
+	fn insert_key_derived(
+		&self,
+		key_type: String,
+		scheme: String,
+		seed_phrase: String,
+		path: Option<String>,
+		password: Option<String>,
+	) -> RpcResult<Bytes> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let key_type = key_type.as_str().try_into().map_err(|_| Error::BadKeyType)?;
+		let mut suri = seed_phrase;
+		if let Some(path) = path {
+			// Anything else would be appended to the seed phrase or read as the password.
+			if !path.starts_with('/') || path.contains("///") {
+				return Err(Error::InvalidDerivationPath.into())
+			}
+			suri.push_str(&path);
+		}
+		if let Some(password) = password {
+			suri.push_str("///");
+			suri.push_str(&password);
+		}
+
+		let public = match scheme.as_str() {
+			"ed25519" => public_from_suri::<ed25519::Pair>(&suri)?,
+			"sr25519" => public_from_suri::<sr25519::Pair>(&suri)?,
+			"ecdsa" => public_from_suri::<ecdsa::Pair>(&suri)?,
+			_ => return Err(Error::BadCryptoScheme.into()),
+		};
+		SyncCryptoStore::insert_unknown(&*self.keystore, key_type, &suri, &public)
+			.map_err(|_| Error::KeyStoreUnavailable)?;
+		Ok(public.into())
+	}
+
 	fn rotate_keys(&self) -> RpcResult<Bytes> {
 		self.deny_unsafe.check_if_safe()?;
 
 		let best_block_hash = self.client.info().best_hash;
+		self.ensure_session_keys_api(best_block_hash)?;
 		self.client
 			.runtime_api()
 			.generate_session_keys(&generic::BlockId::Hash(best_block_hash), None)
@@ -132,6 +625,7 @@ where
 		self.deny_unsafe.check_if_safe()?;
 
 		let best_block_hash = self.client.info().best_hash;
+		self.ensure_session_keys_api(best_block_hash)?;
 		let keys = self
 			.client
 			.runtime_api()
@@ -142,6 +636,26 @@ where
 		Ok(SyncCryptoStore::has_keys(&*self.keystore, &keys))
 	}
 
+	fn required_key_types(&self) -> RpcResult<Vec<String>> {
+		let key_types = self.session_key_types(self.client.info().best_hash)?;
+
+		Ok(key_types
+			.into_iter()
+			.map(|key_type| String::from_utf8_lossy(&key_type.0).into_owned())
+			.collect())
+	}
+
+	fn is_authoring(&self) -> RpcResult<bool> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let runtime_apis = self.runtime_apis.as_ref().ok_or(Error::RuntimeApisUnavailable)?;
+		let best_block_hash = self.client.info().best_hash;
+		Ok(runtime_apis
+			.validator_session_keys(best_block_hash)?
+			.iter()
+			.any(|keys| !keys.is_empty() && SyncCryptoStore::has_keys(&*self.keystore, keys)))
+	}
+
 	fn has_key(&self, public_key: Bytes, key_type: String) -> RpcResult<bool> {
 		self.deny_unsafe.check_if_safe()?;
 
@@ -149,10 +663,159 @@ where
 		Ok(SyncCryptoStore::has_keys(&*self.keystore, &[(public_key.to_vec(), key_type)]))
 	}
 
+	fn extrinsic_era(&self, ext: Bytes) -> RpcResult<era::EraInfo> {
+		let xt = TransactionFor::<P>::decode(&mut &ext[..]).map_err(Error::BadFormat)?;
+		if xt.is_signed() == Some(false) {
+			return Err(Error::UnsignedExtrinsic.into())
+		}
+
+		Ok(match xt.era() {
+			Some(era @ Era::Mortal(period, phase)) => {
+				let best_number = self.client.info().best_number.saturated_into::<u64>();
+				era::EraInfo::Mortal {
+					period,
+					phase,
+					birth: era.birth(best_number),
+					death: era.death(best_number),
+				}
+			},
+			// Extrinsics without an era never expire.
+			Some(Era::Immortal) | None => era::EraInfo::Immortal,
+		})
+	}
+
+	fn dry_run_extrinsic(
+		&self,
+		ext: Bytes,
+		at: Option<BlockHash<P>>,
+	) -> RpcResult<dry_run::DryRunResult> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let runtime_apis = self.runtime_apis.as_ref().ok_or(Error::RuntimeApisUnavailable)?;
+		let xt = TransactionFor::<P>::decode(&mut &ext[..]).map_err(Error::BadFormat)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let (result, events) = runtime_apis.dry_run_extrinsic(at, xt)?;
+
+		Ok(dry_run::DryRunResult { result: result.encode().into(), events: events.into() })
+	}
+
+	fn block_status(&self, hash: BlockHash<P>) -> RpcResult<block_status::BlockStatusInfo> {
+		let number = match self.client.number(hash).map_err(|e| Error::Client(Box::new(e)))? {
+			Some(number) => number,
+			None =>
+				return Ok(block_status::BlockStatusInfo {
+					known: false,
+					number: None,
+					canonical: false,
+					finalized: false,
+				}),
+		};
+		let canonical =
+			self.client.hash(number).map_err(|e| Error::Client(Box::new(e)))? == Some(hash);
+		Ok(block_status::BlockStatusInfo {
+			known: true,
+			number: Some(number.saturated_into()),
+			canonical,
+			finalized: canonical && number <= self.client.info().finalized_number,
+		})
+	}
+
+	fn extrinsic_hash(&self, ext: Bytes) -> RpcResult<TxHash<P>> {
+		let xt = TransactionFor::<P>::decode(&mut &ext[..]).map_err(Error::BadFormat)?;
+		Ok(self.pool.hash_of(&xt))
+	}
+
+	fn extract_call(&self, ext: Bytes) -> RpcResult<Bytes> {
+		let xt = TransactionFor::<P>::decode(&mut &ext[..]).map_err(Error::BadFormat)?;
+		xt.encoded_call().map(Into::into).ok_or_else(|| Error::CallUnavailable.into())
+	}
+
+	fn extrinsic_extensions(&self, ext: Bytes) -> RpcResult<Vec<(String, Bytes)>> {
+		let xt = TransactionFor::<P>::decode(&mut &ext[..]).map_err(Error::BadFormat)?;
+		if xt.is_signed() == Some(false) {
+			return Err(Error::UnsignedExtrinsic.into())
+		}
+
+		let extensions = xt.signed_extensions().ok_or(Error::ExtensionsUnavailable)?;
+		Ok(extensions.into_iter().map(|(id, data)| (id.to_owned(), data.into())).collect())
+	}
+
+	fn keystore_writable(&self) -> RpcResult<bool> {
+		self.deny_unsafe.check_if_safe()?;
+
+		Ok(SyncCryptoStore::is_writable(&*self.keystore))
+	}
+
+	fn active_watches(&self) -> RpcResult<Vec<(TxHash<P>, String)>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		Ok(self.watches.list())
+	}
+
 	fn pending_extrinsics(&self) -> RpcResult<Vec<Bytes>> {
 		Ok(self.pool.ready().map(|tx| tx.data().encode().into()).collect())
 	}
 
+	fn pool_limits(&self) -> RpcResult<PoolLimits> {
+		Ok(self.pool.limits())
+	}
+
+	fn pool_min_priority(&self) -> RpcResult<Option<u64>> {
+		let status = self.pool.status();
+		let limits = self.pool.limits();
+		if status.ready < limits.ready && status.ready_bytes < limits.ready_bytes {
+			return Ok(None)
+		}
+
+		Ok(self.pool.ready().map(|tx| *tx.priority()).min())
+	}
+
+	fn is_in_recent_block(&self, ext: Bytes, depth: u32) -> RpcResult<Option<BlockHash<P>>> {
+		let xt = TransactionFor::<P>::decode(&mut &ext[..]).map_err(Error::BadFormat)?;
+		let hash = self.pool.hash_of(&xt);
+
+		let mut number = self.client.info().finalized_number;
+		for _ in 0..depth.min(RECENT_BLOCKS_MAX_DEPTH) {
+			let block = match self.client.hash(number).map_err(|e| Error::Client(Box::new(e)))? {
+				Some(block) => block,
+				None => break,
+			};
+			let body = self
+				.client
+				.block_body(&block)
+				.map_err(|e| Error::Client(Box::new(e)))?
+				.unwrap_or_default();
+			if body.iter().any(|xt| self.pool.hash_of(xt) == hash) {
+				return Ok(Some(block))
+			}
+			if number.is_zero() {
+				break
+			}
+			number -= One::one();
+		}
+
+		Ok(None)
+	}
+
+	fn recently_finalized(&self, limit: u32) -> RpcResult<Vec<TxHash<P>>> {
+		Ok(self.recently_finalized.latest(limit as usize))
+	}
+
+	fn runtime_spec(&self) -> RpcResult<(u32, u32)> {
+		let best_block_hash = self.client.info().best_hash;
+		let version = self
+			.client
+			.runtime_api()
+			.version(&generic::BlockId::Hash(best_block_hash))
+			.map_err(|e| Error::Client(Box::new(e)))?;
+
+		Ok((version.spec_version, version.transaction_version))
+	}
+
+	fn genesis_hash(&self) -> RpcResult<BlockHash<P>> {
+		Ok(self.client.info().genesis_hash)
+	}
+
 	fn remove_extrinsic(
 		&self,
 		bytes_or_hash: Vec<hash::ExtrinsicOrHash<TxHash<P>>>,
@@ -187,6 +850,15 @@ where
 			},
 		};
 
+		// Like a method call, the subscription submits a single extrinsic.
+		if let Err(e) = self.rate_limiter.acquire(1) {
+			let _ = sink.reject(JsonRpseeError::from(e));
+			return Ok(())
+		}
+
+		let hash = self.pool.hash_of(&dxt);
+		let submitted = self.submitted.clone();
+		let watches = self.watches.clone();
 		let submit = self
 			.pool
 			.submit_and_watch(&generic::BlockId::hash(best_block_hash), TX_SOURCE, dxt)
@@ -204,7 +876,101 @@ where
 					return
 				},
 			};
+			submitted.insert(hash.clone());
 
+			let watch_id = watches.insert(hash);
+			sink.pipe_from_stream(stream).await;
+			watches.remove(watch_id);
+		};
+
+		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+		Ok(())
+	}
+
+	fn submit_and_watch_batch(
+		&self,
+		mut sink: SubscriptionSink,
+		xts: Vec<Bytes>,
+	) -> SubscriptionResult {
+		let best_block_hash = self.client.info().best_hash;
+		let dxts = match xts
+			.iter()
+			.map(|xt| TransactionFor::<P>::decode(&mut &xt[..]))
+			.collect::<std::result::Result<Vec<_>, _>>()
+		{
+			Ok(dxts) => dxts,
+			Err(e) => {
+				let _ = sink.reject(JsonRpseeError::from(Error::from(e)));
+				return Ok(())
+			},
+		};
+
+		let count = u32::try_from(dxts.len()).unwrap_or(u32::MAX);
+		if let Err(e) = self.rate_limiter.acquire(count) {
+			let _ = sink.reject(JsonRpseeError::from(e));
+			return Ok(())
+		}
+
+		let pool = self.pool.clone();
+		let submitted = self.submitted.clone();
+		let fut = async move {
+			let mut streams = Vec::with_capacity(dxts.len());
+			for dxt in dxts {
+				let hash = pool.hash_of(&dxt);
+				let at = generic::BlockId::hash(best_block_hash);
+				match pool.submit_and_watch(&at, TX_SOURCE, dxt).await {
+					Ok(stream) => {
+						submitted.insert(hash.clone());
+						streams.push(stream.map(move |status| (hash.clone(), status)));
+					},
+					Err(e) => {
+						let err = e
+							.into_pool_error()
+							.map(error::Error::from)
+							.unwrap_or_else(|e| error::Error::Verification(Box::new(e)));
+						let _ = sink.reject(JsonRpseeError::from(err));
+						return
+					},
+				}
+			}
+
+			// Each watcher ends once its extrinsic reached a final state.
+			sink.pipe_from_stream(futures::stream::select_all(streams)).await;
+		};
+
+		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+		Ok(())
+	}
+
+	fn watch_my_finalized(&self, mut sink: SubscriptionSink) -> SubscriptionResult {
+		let stream = self.finalized_submissions();
+
+		let fut = async move {
+			sink.pipe_from_stream(stream).await;
+		};
+
+		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+		Ok(())
+	}
+
+	fn watch_pool_status(&self, mut sink: SubscriptionSink) -> SubscriptionResult {
+		let pool = self.pool.clone();
+		let initial = pool.status();
+		let counts = (initial.ready, initial.future);
+		// The status is checked once per imported block.
+		let changes = self
+			.client
+			.import_notification_stream()
+			.scan(counts, move |last, _| {
+				let status = pool.status();
+				let changed = (status.ready, status.future) != *last;
+				*last = (status.ready, status.future);
+				future::ready(Some(changed.then_some(status)))
+			})
+			.filter_map(future::ready);
+		let stream = futures::stream::once(future::ready(initial)).chain(changes);
+
+		let fut = async move {
 			sink.pipe_from_stream(stream).await;
 		};
 
@@ -212,3 +978,265 @@ where
 		Ok(())
 	}
 }
+
+impl<P, Client> Author<P, Client>
+where
+	P: TransactionPool + Sync + Send + 'static,
+	Client: HeaderBackend<P::Block>
+		+ BlockchainEvents<P::Block>
+		+ BlockBackend<P::Block>
+		+ Send
+		+ Sync
+		+ 'static,
+{
+	/// Submit `xt` to the pool at the best block, optionally attaching a `label` to it.
+	///
+	/// The submission counts towards the rate limit of submission methods. Labelled transactions
+	/// are watched, to drop the label once they leave the pool.
+	async fn submit(&self, xt: TransactionFor<P>, label: Option<String>) -> Result<TxHash<P>> {
+		self.rate_limiter.acquire(1)?;
+		let best_block_hash = self.client.info().best_hash;
+		let at = generic::BlockId::hash(best_block_hash);
+		let map_err = |e: P::Error| {
+			e.into_pool_error()
+				.map(|e| Error::Pool(e))
+				.unwrap_or_else(|e| Error::Verification(Box::new(e)))
+		};
+
+		if self.recently_finalized.start_tracking() {
+			self.spawn_track_finalized();
+		}
+
+		let label = match label {
+			Some(label) => label,
+			None => {
+				let hash = self.pool.submit_one(&at, TX_SOURCE, xt).await.map_err(map_err)?;
+				self.submitted.insert(hash.clone());
+				return Ok(hash)
+			},
+		};
+
+		let hash = self.pool.hash_of(&xt);
+		let mut stream = self.pool.submit_and_watch(&at, TX_SOURCE, xt).await.map_err(map_err)?;
+		self.submitted.insert(hash.clone());
+		self.labels.insert(hash.clone(), label);
+		let labels = self.labels.clone();
+		let tx_hash = hash.clone();
+		let fut = async move {
+			// The stream ends once the transaction has left the pool for good.
+			while stream.next().await.is_some() {}
+			labels.remove(&tx_hash);
+		};
+		self.executor.spawn("substrate-rpc-track-label", Some("rpc"), fut.boxed());
+
+		Ok(hash)
+	}
+
+	/// Spawn the task recording the finalized extrinsics submitted through this node.
+	fn spawn_track_finalized(&self) {
+		let recently_finalized = self.recently_finalized.clone();
+		let fut = self.finalized_submissions().for_each(move |finalized| {
+			for hash in finalized.extrinsics {
+				recently_finalized.push(hash);
+			}
+			future::ready(())
+		});
+		self.executor.spawn("substrate-rpc-track-finalized", Some("rpc"), fut.boxed());
+	}
+
+	/// The extrinsics submitted through this node in each newly finalized block.
+	///
+	/// Blocks without such extrinsics are skipped.
+	fn finalized_submissions(
+		&self,
+	) -> impl Stream<Item = finalized::FinalizedExtrinsics<TxHash<P>, BlockHash<P>>> {
+		let client = self.client.clone();
+		let pool = self.pool.clone();
+		let submitted = self.submitted.clone();
+		self.client
+			.finality_notification_stream()
+			.flat_map(|notification| {
+				// Blocks finalized implicitly come first.
+				let blocks = notification
+					.tree_route
+					.iter()
+					.cloned()
+					.chain(std::iter::once(notification.hash))
+					.collect::<Vec<_>>();
+				futures::stream::iter(blocks)
+			})
+			.filter_map(move |block| {
+				let extrinsics = client
+					.block_body(&block)
+					.ok()
+					.flatten()
+					.unwrap_or_default()
+					.iter()
+					.map(|xt| pool.hash_of(xt))
+					.filter(|hash| submitted.contains(hash))
+					.collect::<Vec<_>>();
+				future::ready(
+					(!extrinsics.is_empty())
+						.then(|| finalized::FinalizedExtrinsics { block, extrinsics }),
+				)
+			})
+	}
+}
+
+impl<P, Client> Author<P, Client>
+where
+	P: TransactionPool + Sync + Send + 'static,
+	Client: HeaderBackend<P::Block> + BlockchainEvents<P::Block> + Send + Sync + 'static,
+	<P::Block as BlockT>::Hash: Unpin,
+{
+	/// Submit `ext` and wait until it is included in a block, or in a finalized block if
+	/// `finalized` is set.
+	///
+	/// Gives up once `timeout_blocks` new best blocks have been imported, which must not exceed
+	/// [`MAX_WAIT_TIMEOUT_BLOCKS`].
+	async fn submit_and_wait(
+		&self,
+		ext: Bytes,
+		timeout_blocks: u32,
+		finalized: bool,
+	) -> Result<inclusion::Inclusion<BlockHash<P>>> {
+		if timeout_blocks > MAX_WAIT_TIMEOUT_BLOCKS {
+			return Err(Error::TimeoutTooLarge {
+				requested: timeout_blocks,
+				max: MAX_WAIT_TIMEOUT_BLOCKS,
+			})
+		}
+		let xt = match Decode::decode(&mut &ext[..]) {
+			Ok(xt) => xt,
+			Err(err) => return Err(Error::Client(Box::new(err))),
+		};
+		self.rate_limiter.acquire(1)?;
+		// Subscribe before submitting, so that no block import is missed.
+		let imports = self.client.import_notification_stream();
+		let best_block_hash = self.client.info().best_hash;
+		let hash = self.pool.hash_of(&xt);
+		let stream = self
+			.pool
+			.submit_and_watch(&generic::BlockId::hash(best_block_hash), TX_SOURCE, xt)
+			.await
+			.map_err(|e| {
+				e.into_pool_error()
+					.map(Error::from)
+					.unwrap_or_else(|e| Error::Verification(Box::new(e)))
+			})?;
+		self.submitted.insert(hash);
+
+		let new_blocks = imports.filter(|n| future::ready(n.is_new_best)).map(|_| ());
+		let (tx, rx) = oneshot::channel();
+		let fut = async move {
+			let _ = tx.send(wait_for_block(stream, new_blocks, timeout_blocks, finalized).await);
+		};
+		self.executor.spawn("substrate-rpc-submit-and-wait", Some("rpc"), fut.boxed());
+
+		rx.await.map_err(|e| Error::Client(Box::new(e)))?
+	}
+}
+
+impl<P, Client> Author<P, Client>
+where
+	P: TransactionPool + Sync + Send + 'static,
+	Client: ProvideRuntimeApi<P::Block> + Send + Sync + 'static,
+	Client::Api: SessionKeys<P::Block>,
+{
+	/// Ensure the runtime at `at` provides the [`SessionKeys`] API.
+	fn ensure_session_keys_api(&self, at: BlockHash<P>) -> Result<()> {
+		let version = self
+			.client
+			.runtime_api()
+			.version(&generic::BlockId::Hash(at))
+			.map_err(|e| Error::Client(Box::new(e)))?;
+
+		check_session_keys_api::<P::Block>(&version)
+	}
+
+	/// The key types of the session keys of the runtime at `at`.
+	fn session_key_types(&self, at: BlockHash<P>) -> Result<Vec<KeyTypeId>> {
+		self.ensure_session_keys_api(at)?;
+		// Session keys are made of fixed size public keys, so decoding a long enough zeroed
+		// blob yields their key types without touching the keystore.
+		let keys = self
+			.client
+			.runtime_api()
+			.decode_session_keys(&generic::BlockId::Hash(at), vec![0; SESSION_KEYS_MAX_ENCODED_LEN])
+			.map_err(|e| Error::Client(Box::new(e)))?
+			.ok_or(Error::InvalidSessionKeys)?;
+
+		Ok(keys.into_iter().map(|(_, key_type)| key_type).collect())
+	}
+}
+
+/// The error reported for a transaction found invalid by `validate_transaction`.
+fn validity_error(e: TransactionValidityError) -> Error {
+	Error::Pool(match e {
+		TransactionValidityError::Invalid(e) => PoolError::InvalidTransaction(e),
+		TransactionValidityError::Unknown(e) => PoolError::UnknownTransaction(e),
+	})
+}
+
+/// Like [`validity_error`], but with a dedicated error for senders that cannot pay.
+fn checked_validity_error(e: TransactionValidityError) -> Error {
+	match e {
+		TransactionValidityError::Invalid(InvalidTransaction::Payment) => Error::Payment,
+		e => validity_error(e),
+	}
+}
+
+/// The raw public key of the key pair of the given `suri`.
+fn public_from_suri<P: Pair>(suri: &str) -> Result<Vec<u8>> {
+	P::from_string(suri, None)
+		.map(|pair| pair.public().as_ref().to_vec())
+		.map_err(|_| Error::BadSecretUri)
+}
+
+/// Check that a runtime of the given `version` provides the [`SessionKeys`] API.
+fn check_session_keys_api<Block: BlockT>(version: &RuntimeVersion) -> Result<()> {
+	if version.has_api_with(&<dyn SessionKeys<Block>>::ID, |_| true) {
+		Ok(())
+	} else {
+		Err(Error::SessionKeysApiUnavailable)
+	}
+}
+
+/// Follow the status `stream` of a submitted transaction until it is included in a block, or in a
+/// finalized block if `finalized` is set.
+///
+/// Returns the block along with whether it was reported finalized. Gives up once
+/// `timeout_blocks` items have been yielded by `new_blocks` without the transaction reaching the
+/// awaited state.
+async fn wait_for_block<Hash, BlockHash>(
+	stream: impl Stream<Item = TransactionStatus<Hash, BlockHash>> + Unpin,
+	new_blocks: impl Stream<Item = ()> + Unpin,
+	timeout_blocks: u32,
+	finalized: bool,
+) -> Result<inclusion::Inclusion<BlockHash>> {
+	let mut events = futures::stream::select(stream.map(Some), new_blocks.map(|_| None));
+	let mut blocks = 0u32;
+
+	while let Some(event) = events.next().await {
+		match event {
+			Some(TransactionStatus::InBlock((block, _))) if !finalized =>
+				return Ok(inclusion::Inclusion { block, finalized: false }),
+			Some(TransactionStatus::Finalized((block, _))) =>
+				return Ok(inclusion::Inclusion { block, finalized: true }),
+			Some(TransactionStatus::Dropped) => return Err(Error::TransactionLeftPool("dropped")),
+			Some(TransactionStatus::Invalid) => return Err(Error::TransactionLeftPool("invalid")),
+			Some(TransactionStatus::Usurped(_)) =>
+				return Err(Error::TransactionLeftPool("usurped")),
+			Some(TransactionStatus::FinalityTimeout(_)) => return Err(Error::FinalityTimeout),
+			Some(_) => {},
+			None => {
+				blocks = blocks.saturating_add(1);
+				if blocks >= timeout_blocks {
+					break
+				}
+			},
+		}
+	}
+
+	Err(Error::WaitTimeout(timeout_blocks))
+}
@@ -26,22 +26,31 @@ use jsonrpsee::{
 	types::{error::CallError, EmptyParams},
 	RpcModule,
 };
-use sc_transaction_pool::{BasicPool, FullChainApi};
-use sc_transaction_pool_api::TransactionStatus;
+use sc_block_builder::BlockBuilderProvider;
+use sc_rpc_api::author::{
+	block_status::BlockStatusInfo, era::EraInfo, finalized::FinalizedExtrinsics,
+	inclusion::Inclusion,
+};
+use sc_transaction_pool::{BasicPool, FullChainApi, Options};
+use sc_transaction_pool_api::{
+	ChainEvent, MaintainedTransactionPool, PoolStatus, TransactionStatus,
+};
+use sp_consensus::BlockOrigin;
 use sp_core::{
 	blake2_256,
 	bytes::to_hex,
-	crypto::{ByteArray, CryptoTypePublicPair, Pair},
+	crypto::{key_types, ByteArray, CryptoTypePublicPair, Pair, DEV_PHRASE},
 	ed25519, sr25519,
-	testing::{ED25519, SR25519},
+	testing::{ECDSA, ED25519, SR25519},
 	H256,
 };
 use sp_keystore::testing::KeyStore;
 use std::sync::Arc;
 use substrate_test_runtime_client::{
 	self,
-	runtime::{Block, Extrinsic, SessionKeys, Transfer},
-	AccountKeyring, Backend, Client, DefaultTestClientBuilderExt, TestClientBuilderExt,
+	runtime::{Block, Extrinsic, RuntimeEvent, SessionKeys, TestAPI, Transfer},
+	AccountKeyring, Backend, Client, ClientBlockImportExt, ClientExt, DefaultTestClientBuilderExt,
+	TestClientBuilderExt,
 };
 
 fn uxt(sender: AccountKeyring, nonce: u64) -> Extrinsic {
@@ -64,18 +73,21 @@ struct TestSetup {
 
 impl Default for TestSetup {
 	fn default() -> Self {
+		Self::with_pool_options(Default::default())
+	}
+}
+
+impl TestSetup {
+	fn with_pool_options(options: Options) -> Self {
 		let keystore = Arc::new(KeyStore::new());
 		let client_builder = substrate_test_runtime_client::TestClientBuilder::new();
 		let client = Arc::new(client_builder.set_keystore(keystore.clone()).build());
 
 		let spawner = sp_core::testing::TaskExecutor::new();
-		let pool =
-			BasicPool::new_full(Default::default(), true.into(), None, spawner, client.clone());
+		let pool = BasicPool::new_full(options, true.into(), None, spawner, client.clone());
 		TestSetup { client, keystore, pool }
 	}
-}
 
-impl TestSetup {
 	fn author(&self) -> Author<FullTransactionPool, Client<Backend>> {
 		Author {
 			client: self.client.clone(),
@@ -83,12 +95,40 @@ impl TestSetup {
 			keystore: self.keystore.clone(),
 			deny_unsafe: DenyUnsafe::No,
 			executor: test_executor(),
+			recently_finalized: Arc::new(RecentlyFinalized::new(RECENTLY_FINALIZED_CAPACITY)),
+			labels: Arc::new(SubmissionLabels::new(SUBMISSION_LABELS_CAPACITY)),
+			submitted: Arc::new(SubmittedHashes::new(SUBMITTED_HASHES_CAPACITY)),
+			rate_limiter: Arc::new(RateLimiter::new(None)),
+			watches: Arc::new(ActiveWatches::new()),
+			runtime_apis: Some(self.client.clone()),
 		}
 	}
 
 	fn into_rpc() -> RpcModule<Author<FullTransactionPool, Client<Backend>>> {
 		Self::default().author().into_rpc()
 	}
+
+	/// Import a block with the given extrinsics on top of the best block and let the pool know.
+	async fn import_block(&self, xts: Vec<Extrinsic>, finalize: bool) -> H256 {
+		let mut builder = self.client.new_block(Default::default()).unwrap();
+		for xt in xts {
+			builder.push(xt).unwrap();
+		}
+		let block = builder.build().unwrap().block;
+		let hash = block.header.hash();
+
+		self.client.clone().import(BlockOrigin::Own, block).await.unwrap();
+		self.pool.maintain(ChainEvent::NewBestBlock { hash, tree_route: None }).await;
+
+		if finalize {
+			self.client.finalize_block(&hash, None).unwrap();
+			self.pool
+				.maintain(ChainEvent::Finalized { hash, tree_route: Arc::from(vec![]) })
+				.await;
+		}
+
+		hash
+	}
 }
 
 #[tokio::test]
@@ -108,6 +148,54 @@ async fn author_submit_transaction_should_not_cause_error() {
 	);
 }
 
+#[tokio::test]
+async fn author_submit_idempotent_should_return_existing_hash() {
+	let api = TestSetup::into_rpc();
+	let xt: Bytes = uxt(AccountKeyring::Alice, 1).encode().into();
+	let extrinsic_hash: H256 = blake2_256(&xt).into();
+
+	let first: H256 = api.call("author_submitExtrinsicIdempotent", [xt.clone()]).await.unwrap();
+	let second: H256 = api.call("author_submitExtrinsicIdempotent", [xt]).await.unwrap();
+
+	assert_eq!(first, extrinsic_hash);
+	assert_eq!(second, extrinsic_hash);
+
+	// Validation failures are still reported.
+	assert_matches!(
+		api.call::<_, H256>("author_submitExtrinsicIdempotent", [to_hex(&uxt(AccountKeyring::Alice, 179).encode(), true)]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.message().contains("Invalid Transaction") && err.code() == 1010
+	);
+}
+
+#[tokio::test]
+async fn author_submit_checked_should_validate_first() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+
+	// Once included, the transfer is stale.
+	let xt: Bytes = uxt(AccountKeyring::Alice, 0).encode().into();
+	setup.import_block(vec![uxt(AccountKeyring::Alice, 0)], false).await;
+	assert_matches!(
+		api.call::<_, H256>("author_submitExtrinsicChecked", [xt]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.code() == 1010 && err.data().unwrap().get().contains("outdated")
+	);
+	assert_eq!(setup.pool.status().ready, 0);
+
+	let xt: Bytes = uxt(AccountKeyring::Alice, 1).encode().into();
+	let hash: H256 = api.call("author_submitExtrinsicChecked", [xt.clone()]).await.unwrap();
+	assert_eq!(hash, blake2_256(&xt).into());
+	assert_eq!(setup.pool.status().ready, 1);
+}
+
+#[test]
+fn checked_validity_error_reports_payment() {
+	assert_matches!(checked_validity_error(InvalidTransaction::Payment.into()), Error::Payment);
+	assert_matches!(
+		checked_validity_error(InvalidTransaction::Stale.into()),
+		Error::Pool(PoolError::InvalidTransaction(InvalidTransaction::Stale))
+	);
+}
+
 #[tokio::test]
 async fn author_should_watch_extrinsic() {
 	let api = TestSetup::into_rpc();
@@ -148,6 +236,442 @@ async fn author_should_watch_extrinsic() {
 	assert_eq!(&sub_id, sub.subscription_id());
 }
 
+#[tokio::test]
+async fn author_should_rate_limit_submissions() {
+	let setup = TestSetup::default();
+	let rate_limiter = Arc::new(RateLimiter::new(NonZeroU32::new(2)));
+	let author = Author { rate_limiter: rate_limiter.clone(), ..setup.author() };
+	let api = author.into_rpc();
+	let xt = |nonce| to_hex(&uxt(AccountKeyring::Alice, nonce).encode(), true);
+
+	// The budget allows a burst of two submissions.
+	let _: H256 = api.call("author_submitExtrinsic", [xt(0)]).await.unwrap();
+	let mut sub = api.subscribe("author_submitAndWatchExtrinsic", [xt(1)]).await.unwrap();
+	assert_matches!(
+		timeout_secs(10, sub.next::<TransactionStatus<H256, H256>>()).await,
+		Ok(Some(Ok((TransactionStatus::Ready, _))))
+	);
+
+	// Then submissions are refused until the bucket refills, whichever method is used.
+	assert_matches!(
+		api.call::<_, H256>("author_submitExtrinsic", [xt(2)]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.code() == 1005 &&
+			err.data().map_or(false, |data| data.get().parse::<u64>().unwrap() <= 500)
+	);
+	assert_matches!(
+		api.subscribe("author_submitAndWatchExtrinsic", [xt(2)]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.code() == 1005
+	);
+	let best = serde_json::json!(setup.client.info().best_hash);
+	for (method, extra) in [
+		("author_submitExtrinsicIdempotent", None),
+		("author_submitExtrinsicLabeled", Some(serde_json::json!("label"))),
+		("author_submitExtrinsicChecked", None),
+		("author_submitExtrinsicIfBest", Some(best)),
+		("author_submitExtrinsicAndWait", Some(serde_json::json!(1))),
+		("author_submitExtrinsicInBlock", Some(serde_json::json!(1))),
+		("author_submitExtrinsicWithPriority", Some(serde_json::json!(100))),
+		("author_submitExtrinsicReceipt", None),
+	] {
+		// Empty the bucket, and keep it from refilling while the call is processed.
+		let refill_at = Instant::now() + std::time::Duration::from_secs(60);
+		*rate_limiter.bucket.lock() = (0.0, refill_at);
+		let params = std::iter::once(serde_json::json!(xt(2))).chain(extra).collect::<Vec<_>>();
+		assert_matches!(
+			api.call::<_, serde_json::Value>(method, params).await,
+			Err(RpcError::Call(CallError::Custom(err))) if err.code() == 1005,
+			"{} is not rate limited",
+			method
+		);
+	}
+	assert_eq!(setup.pool.status().ready, 2);
+
+	// A batch takes its extrinsics from the same bucket.
+	assert_matches!(
+		api.subscribe("author_submitAndWatchExtrinsicBatch", [vec![xt(2)]]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.code() == 1005
+	);
+	let refill_at = Instant::now() + std::time::Duration::from_secs(60);
+	*rate_limiter.bucket.lock() = (2.0, refill_at);
+	assert_matches!(
+		api.subscribe("author_submitAndWatchExtrinsicBatch", [vec![xt(2), xt(3), xt(4)]]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.code() == 1005
+	);
+	let mut sub = api
+		.subscribe("author_submitAndWatchExtrinsicBatch", [vec![xt(2), xt(3)]])
+		.await
+		.unwrap();
+	for _ in 0..2 {
+		assert_matches!(
+			timeout_secs(10, sub.next::<(H256, TransactionStatus<H256, H256>)>()).await,
+			Ok(Some(Ok(((_, TransactionStatus::Ready), _))))
+		);
+	}
+	assert_eq!(setup.pool.status().ready, 4);
+	assert_matches!(
+		api.call::<_, H256>("author_submitExtrinsic", [xt(4)]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.code() == 1005
+	);
+}
+
+#[tokio::test]
+async fn author_should_list_active_watches() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let xt = uxt(AccountKeyring::Alice, 0);
+	let xt_hash = H256::from(blake2_256(&xt.encode()));
+
+	let mut sub = api
+		.subscribe("author_submitAndWatchExtrinsic", [to_hex(&xt.encode(), true)])
+		.await
+		.unwrap();
+	let (status, _) = timeout_secs(10, sub.next::<TransactionStatus<H256, H256>>())
+		.await
+		.unwrap()
+		.unwrap()
+		.unwrap();
+	assert_eq!(status, TransactionStatus::Ready);
+
+	let watches: Vec<(H256, String)> =
+		api.call("author_activeWatches", EmptyParams::new()).await.unwrap();
+	assert_eq!(watches, vec![(xt_hash, "0".to_string())]);
+
+	// The watch is dropped once the extrinsic is finalized.
+	setup.import_block(vec![xt], true).await;
+	while timeout_secs(10, sub.next::<TransactionStatus<H256, H256>>())
+		.await
+		.unwrap()
+		.is_some()
+	{}
+	let active_watches = || async {
+		api.call::<_, Vec<(H256, String)>>("author_activeWatches", EmptyParams::new())
+			.await
+			.unwrap()
+	};
+	timeout_secs(10, async {
+		while !active_watches().await.is_empty() {
+			tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.unwrap();
+}
+
+#[tokio::test]
+async fn author_should_watch_extrinsic_batch() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let xt1 = uxt(AccountKeyring::Alice, 0);
+	let xt2 = uxt(AccountKeyring::Bob, 0);
+	let hash1 = H256::from(blake2_256(&xt1.encode()));
+	let hash2 = H256::from(blake2_256(&xt2.encode()));
+
+	let mut sub = api
+		.subscribe(
+			"author_submitAndWatchExtrinsicBatch",
+			[vec![to_hex(&xt1.encode(), true), to_hex(&xt2.encode(), true)]],
+		)
+		.await
+		.unwrap();
+	let mut updates = Vec::new();
+	for _ in 0..2 {
+		let (update, _) = timeout_secs(10, sub.next::<(H256, TransactionStatus<H256, H256>)>())
+			.await
+			.unwrap()
+			.unwrap()
+			.unwrap();
+		updates.push(update);
+	}
+
+	let mut ready = std::mem::take(&mut updates);
+	ready.sort_by_key(|(hash, _)| *hash == hash2);
+	assert_eq!(ready, vec![(hash1, TransactionStatus::Ready), (hash2, TransactionStatus::Ready)]);
+
+	let block = setup.import_block(vec![xt1, xt2], true).await;
+	while let Some(update) = timeout_secs(10, sub.next::<(H256, TransactionStatus<H256, H256>)>())
+		.await
+		.unwrap()
+	{
+		updates.push(update.unwrap().0);
+	}
+
+	// Both extrinsics are tracked, and the subscription ends once they are finalized.
+	for hash in [hash1, hash2] {
+		let statuses =
+			updates.iter().filter(|(h, _)| *h == hash).map(|(_, s)| s).collect::<Vec<_>>();
+		assert_matches!(
+			statuses[..],
+			[TransactionStatus::InBlock((b1, _)), TransactionStatus::Finalized((b2, _))]
+				if *b1 == block && *b2 == block
+		);
+	}
+}
+
+#[tokio::test]
+async fn author_should_submit_only_if_best_block_matches() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let genesis = setup.client.info().best_hash;
+	let best = setup.import_block(vec![], false).await;
+
+	// A stale expectation submits nothing.
+	let xt = to_hex(&uxt(AccountKeyring::Alice, 0).encode(), true);
+	assert_matches!(
+		api.call::<_, H256>("author_submitExtrinsicIfBest", (xt.clone(), genesis)).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.code() == 1004
+	);
+	assert_eq!(setup.pool.status().ready, 0);
+
+	let _: H256 = api.call("author_submitExtrinsicIfBest", (xt, best)).await.unwrap();
+	assert_eq!(setup.pool.status().ready, 1);
+}
+
+#[tokio::test]
+async fn author_should_submit_and_wait_for_finality() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let xt = uxt(AccountKeyring::Alice, 0);
+	let mut imported = setup.pool.import_notification_stream();
+
+	let params = (to_hex(&xt.encode(), true), 10);
+	let mut wait =
+		tokio::spawn(
+			async move { api.call::<_, H256>("author_submitExtrinsicAndWait", params).await },
+		);
+	timeout_secs(10, imported.next()).await.unwrap().unwrap();
+
+	// Not resolved by inclusion alone.
+	let included = setup.import_block(vec![xt], false).await;
+	assert!(timeout_secs(1, &mut wait).await.is_err());
+
+	setup.client.finalize_block(&included, None).unwrap();
+	setup
+		.pool
+		.maintain(ChainEvent::Finalized { hash: included, tree_route: Arc::from(vec![]) })
+		.await;
+
+	let finalized = timeout_secs(10, wait).await.unwrap().unwrap().unwrap();
+	assert_eq!(finalized, included);
+}
+
+#[tokio::test]
+async fn author_should_submit_and_wait_for_inclusion() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let xt = uxt(AccountKeyring::Alice, 0);
+	let mut imported = setup.pool.import_notification_stream();
+
+	let params = (to_hex(&xt.encode(), true), 10);
+	let wait = tokio::spawn(async move {
+		api.call::<_, Inclusion<H256>>("author_submitExtrinsicInBlock", params).await
+	});
+	timeout_secs(10, imported.next()).await.unwrap().unwrap();
+
+	// Resolved by inclusion, without waiting for finality.
+	let included = setup.import_block(vec![xt], false).await;
+	let in_block = timeout_secs(10, wait).await.unwrap().unwrap().unwrap();
+	assert_eq!(in_block, Inclusion { block: included, finalized: false });
+	assert!(setup.client.info().finalized_hash != included);
+}
+
+#[tokio::test]
+async fn wait_for_block_reports_finality() {
+	let block = H256::repeat_byte(1);
+	let statuses = || {
+		futures::stream::iter(vec![
+			TransactionStatus::<H256, H256>::Ready,
+			TransactionStatus::Finalized((block, 0)),
+		])
+	};
+
+	let inclusion = wait_for_block(statuses(), futures::stream::pending(), 1, false).await;
+	assert_eq!(inclusion.unwrap(), Inclusion { block, finalized: true });
+	let inclusion = wait_for_block(statuses(), futures::stream::pending(), 1, true).await;
+	assert_eq!(inclusion.unwrap(), Inclusion { block, finalized: true });
+}
+
+#[tokio::test]
+async fn author_submit_and_wait_should_time_out() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let mut imported = setup.pool.import_notification_stream();
+
+	let params = (to_hex(&uxt(AccountKeyring::Alice, 0).encode(), true), 2);
+	let wait =
+		tokio::spawn(
+			async move { api.call::<_, H256>("author_submitExtrinsicAndWait", params).await },
+		);
+	timeout_secs(10, imported.next()).await.unwrap().unwrap();
+
+	// Two blocks that don't include the transaction.
+	setup.import_block(vec![], true).await;
+	setup.import_block(vec![], true).await;
+
+	assert_matches!(
+		timeout_secs(10, wait).await.unwrap().unwrap(),
+		Err(RpcError::Call(CallError::Custom(err))) if err.message().contains("within 2 blocks")
+	);
+}
+
+#[tokio::test]
+async fn author_submit_and_wait_should_reject_large_timeout() {
+	let api = TestSetup::into_rpc();
+
+	let xt = to_hex(&uxt(AccountKeyring::Alice, 0).encode(), true);
+	for method in ["author_submitExtrinsicAndWait", "author_submitExtrinsicInBlock"] {
+		let params = (xt.clone(), MAX_WAIT_TIMEOUT_BLOCKS + 1);
+		assert_matches!(
+			api.call::<_, serde_json::Value>(method, params).await,
+			Err(RpcError::Call(CallError::Custom(err))) if err.message().contains("exceeds the maximum")
+		);
+	}
+}
+
+#[tokio::test]
+async fn wait_for_block_reports_finality_timeout() {
+	let block = H256::repeat_byte(1);
+	let statuses = futures::stream::iter(vec![
+		TransactionStatus::<H256, H256>::InBlock((block, 0)),
+		TransactionStatus::FinalityTimeout(block),
+	]);
+
+	assert_matches!(
+		wait_for_block(statuses, futures::stream::pending(), 10, true).await,
+		Err(Error::FinalityTimeout)
+	);
+}
+
+#[tokio::test]
+async fn author_should_track_recently_finalized() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let xt = uxt(AccountKeyring::Alice, 0);
+
+	let hash: H256 =
+		api.call("author_submitExtrinsic", [to_hex(&xt.encode(), true)]).await.unwrap();
+	let finalized: Vec<H256> = api.call("author_recentlyFinalized", [10]).await.unwrap();
+	assert!(finalized.is_empty());
+
+	setup.import_block(vec![xt], true).await;
+
+	// The finalized status is picked up by a background task.
+	let finalized = timeout_secs(10, async {
+		loop {
+			let finalized: Vec<H256> = api.call("author_recentlyFinalized", [10]).await.unwrap();
+			if !finalized.is_empty() {
+				break finalized
+			}
+			tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.unwrap();
+	assert_eq!(finalized, vec![hash]);
+
+	let finalized: Vec<H256> = api.call("author_recentlyFinalized", [0]).await.unwrap();
+	assert!(finalized.is_empty());
+}
+
+#[tokio::test]
+async fn author_should_notify_finalized_submissions() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let mut sub = api.subscribe("author_subscribeMyFinalized", EmptyParams::new()).await.unwrap();
+
+	let xt = uxt(AccountKeyring::Alice, 0);
+	let hash: H256 =
+		api.call("author_submitExtrinsic", [to_hex(&xt.encode(), true)]).await.unwrap();
+	// Not submitted through this node.
+	let other = uxt(AccountKeyring::Bob, 0);
+
+	// Blocks without submitted extrinsics are skipped.
+	setup.import_block(vec![], true).await;
+	let finalized = setup.import_block(vec![xt, other], true).await;
+
+	let (notification, _) = timeout_secs(10, sub.next::<FinalizedExtrinsics<H256, H256>>())
+		.await
+		.unwrap()
+		.unwrap()
+		.unwrap();
+	assert_eq!(notification, FinalizedExtrinsics { block: finalized, extrinsics: vec![hash] });
+}
+
+#[tokio::test]
+async fn author_should_notify_pool_status_changes() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let mut sub = api.subscribe("author_subscribePoolStatus", EmptyParams::new()).await.unwrap();
+
+	// The current status is sent on subscribe.
+	let (status, _) = timeout_secs(10, sub.next::<PoolStatus>()).await.unwrap().unwrap().unwrap();
+	assert_eq!((status.ready, status.future), (0, 0));
+
+	for who in [AccountKeyring::Alice, AccountKeyring::Bob] {
+		let xt = to_hex(&uxt(who, 0).encode(), true);
+		let _: H256 = api.call("author_submitExtrinsic", [xt]).await.unwrap();
+	}
+	// Nothing is sent until a block is imported.
+	assert!(timeout_secs(1, sub.next::<PoolStatus>()).await.is_err());
+
+	// Both imports are reported at once.
+	setup.import_block(vec![], false).await;
+	let (status, _) = timeout_secs(10, sub.next::<PoolStatus>()).await.unwrap().unwrap().unwrap();
+	assert_eq!((status.ready, status.future), (2, 0));
+
+	// Blocks that don't change the status are not reported.
+	setup.import_block(vec![], false).await;
+	assert!(timeout_secs(1, sub.next::<PoolStatus>()).await.is_err());
+}
+
+#[tokio::test]
+async fn author_should_label_extrinsics() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let xt = uxt(AccountKeyring::Alice, 0);
+
+	let hash: H256 = api
+		.call("author_submitExtrinsicLabeled", (to_hex(&xt.encode(), true), "batch-1"))
+		.await
+		.unwrap();
+	let label: Option<String> = api.call("author_labelOf", [hash]).await.unwrap();
+	assert_eq!(label.as_deref(), Some("batch-1"));
+
+	// Unknown extrinsics have no label.
+	let label: Option<String> = api.call("author_labelOf", [H256::repeat_byte(1)]).await.unwrap();
+	assert_eq!(label, None);
+
+	// The label is dropped by a background task once the extrinsic is finalized.
+	setup.import_block(vec![xt], true).await;
+	timeout_secs(10, async {
+		loop {
+			let label: Option<String> = api.call("author_labelOf", [hash]).await.unwrap();
+			if label.is_none() {
+				break
+			}
+			tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.unwrap();
+}
+
+#[test]
+fn submission_labels_should_evict_oldest() {
+	let labels = SubmissionLabels::new(2);
+	labels.insert(1, "one".into());
+	labels.insert(2, "two".into());
+	labels.insert(3, "three".into());
+
+	assert_eq!(labels.get(&1), None);
+	assert_eq!(labels.get(&2).as_deref(), Some("two"));
+	assert_eq!(labels.get(&3).as_deref(), Some("three"));
+
+	labels.remove(&2);
+	assert_eq!(labels.get(&2), None);
+	labels.insert(4, "four".into());
+	assert_eq!(labels.get(&3).as_deref(), Some("three"));
+}
+
 #[tokio::test]
 async fn author_should_return_watch_validation_error() {
 	const METHOD: &'static str = "author_submitAndWatchExtrinsic";
@@ -177,6 +701,119 @@ async fn author_should_return_pending_extrinsics() {
 	assert_eq!(pending, vec![xt_bytes]);
 }
 
+#[tokio::test]
+async fn author_should_submit_extrinsic_with_priority() {
+	let api = TestSetup::into_rpc();
+	// The test runtime uses the transferred amount as priority.
+	let transfer = |sender: AccountKeyring, amount| {
+		let tx =
+			Transfer { amount, nonce: 0, from: sender.into(), to: AccountKeyring::Ferdie.into() };
+		tx.into_signed_tx().encode()
+	};
+	let normal = transfer(AccountKeyring::Alice, 5);
+	let bumped = transfer(AccountKeyring::Bob, 1);
+
+	let _: H256 = api.call("author_submitExtrinsic", [to_hex(&normal, true)]).await.unwrap();
+	let _: H256 = api
+		.call("author_submitExtrinsicWithPriority", (to_hex(&bumped, true), 100u64))
+		.await
+		.unwrap();
+
+	let pending: Vec<Bytes> =
+		api.call("author_pendingExtrinsics", EmptyParams::new()).await.unwrap();
+	assert_eq!(pending, vec![bumped.into(), normal.into()]);
+}
+
+#[tokio::test]
+async fn author_submit_receipt_should_report_validation() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let tx = Transfer {
+		amount: 7,
+		nonce: 0,
+		from: AccountKeyring::Alice.into(),
+		to: AccountKeyring::Bob.into(),
+	}
+	.into_signed_tx();
+
+	let receipt: receipt::SubmitReceipt<H256, H256> = api
+		.call("author_submitExtrinsicReceipt", [to_hex(&tx.encode(), true)])
+		.await
+		.unwrap();
+	let info = setup.client.info();
+	assert_eq!(
+		receipt,
+		receipt::SubmitReceipt {
+			hash: setup.pool.hash_of(&tx),
+			validated_at: info.best_hash,
+			// The test runtime uses the transferred amount as priority.
+			priority: 7,
+			longevity: 64,
+			genesis_hash: info.genesis_hash,
+		}
+	);
+	assert_eq!(setup.pool.status().ready, 1);
+}
+
+#[tokio::test]
+async fn author_should_return_block_status() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+
+	// Block 1 is finalized, block 2 is only best, and a sibling of block 1 is on a fork.
+	let finalized = setup.import_block(vec![], false).await;
+	let mut builder = setup
+		.client
+		.new_block_at(&generic::BlockId::Number(0), Default::default(), false)
+		.unwrap();
+	builder.push(uxt(AccountKeyring::Alice, 0)).unwrap();
+	let fork = builder.build().unwrap().block;
+	let fork_hash = fork.header.hash();
+	setup.client.clone().import(BlockOrigin::Own, fork).await.unwrap();
+	let best = setup.import_block(vec![], false).await;
+	setup.client.finalize_block(&finalized, None).unwrap();
+
+	let status = |hash: H256| api.call::<_, BlockStatusInfo>("author_blockStatus", [hash]);
+	let info = |number, canonical, finalized| BlockStatusInfo {
+		known: true,
+		number: Some(number),
+		canonical,
+		finalized,
+	};
+	assert_eq!(status(finalized).await.unwrap(), info(1, true, true));
+	assert_eq!(status(best).await.unwrap(), info(2, true, false));
+	assert_eq!(status(fork_hash).await.unwrap(), info(1, false, false));
+	assert_eq!(
+		status(H256::repeat_byte(0x42)).await.unwrap(),
+		BlockStatusInfo { known: false, number: None, canonical: false, finalized: false }
+	);
+}
+
+#[tokio::test]
+async fn author_should_find_extrinsic_in_recent_block() {
+	const METHOD: &'static str = "author_isInRecentBlock";
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+
+	let xt = uxt(AccountKeyring::Alice, 0);
+	let included = setup.import_block(vec![xt.clone()], true).await;
+	setup.import_block(vec![], true).await;
+
+	let xt = to_hex(&xt.encode(), true);
+	let found: Option<H256> = api.call(METHOD, (xt.clone(), 2)).await.unwrap();
+	assert_eq!(found, Some(included));
+	let found: Option<H256> = api.call(METHOD, (xt.clone(), u32::MAX)).await.unwrap();
+	assert_eq!(found, Some(included));
+
+	// Only the last finalized block is scanned.
+	let found: Option<H256> = api.call(METHOD, (xt, 1)).await.unwrap();
+	assert_eq!(found, None);
+
+	let other = to_hex(&uxt(AccountKeyring::Bob, 0).encode(), true);
+	let found: Option<H256> = api.call(METHOD, (other, 2)).await.unwrap();
+	assert_eq!(found, None);
+}
+
 #[tokio::test]
 async fn author_should_remove_extrinsics() {
 	const METHOD: &'static str = "author_removeExtrinsic";
@@ -213,6 +850,242 @@ async fn author_should_remove_extrinsics() {
 	assert_eq!(removed, vec![xt1_hash, xt2_hash, xt3_hash]);
 }
 
+#[tokio::test]
+async fn author_should_revalidate_pool() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+
+	let xt = to_hex(&uxt(AccountKeyring::Alice, 0).encode(), true);
+	let _: H256 = api.call("author_submitExtrinsic", [xt]).await.unwrap();
+	assert_eq!(setup.pool.status().ready, 1);
+
+	// Nothing is invalid yet.
+	let dropped: u64 = api.call("author_revalidatePool", EmptyParams::new()).await.unwrap();
+	assert_eq!(dropped, 0);
+	assert_eq!(setup.pool.status().ready, 1);
+
+	// Import a block using up Alice's nonce 0 without telling the pool about it.
+	let conflicting = Transfer {
+		amount: 1,
+		nonce: 0,
+		from: AccountKeyring::Alice.into(),
+		to: AccountKeyring::Charlie.into(),
+	}
+	.into_signed_tx();
+	let mut builder = setup.client.new_block(Default::default()).unwrap();
+	builder.push(conflicting).unwrap();
+	let block = builder.build().unwrap().block;
+	setup.client.clone().import(BlockOrigin::Own, block).await.unwrap();
+	assert_eq!(setup.pool.status().ready, 1);
+
+	let dropped: u64 = api.call("author_revalidatePool", EmptyParams::new()).await.unwrap();
+	assert_eq!(dropped, 1);
+	assert_eq!(setup.pool.status().ready, 0);
+}
+
+#[tokio::test]
+async fn author_should_return_pool_limits() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+
+	let limits: PoolLimits = api.call("author_poolLimits", EmptyParams::new()).await.unwrap();
+	let options = Options::default();
+	assert_eq!(
+		limits,
+		PoolLimits {
+			ready: options.ready.count,
+			ready_bytes: options.ready.total_bytes,
+			future: options.future.count,
+			future_bytes: options.future.total_bytes,
+		}
+	);
+}
+
+#[tokio::test]
+async fn author_should_return_pool_min_priority() {
+	let mut options = Options::default();
+	options.ready.count = 3;
+	let setup = TestSetup::with_pool_options(options);
+	let api = setup.author().into_rpc();
+	// The test runtime uses the transferred amount as priority.
+	let transfer = |sender: AccountKeyring, amount| {
+		let tx =
+			Transfer { amount, nonce: 0, from: sender.into(), to: AccountKeyring::Ferdie.into() };
+		to_hex(&tx.into_signed_tx().encode(), true)
+	};
+
+	for (sender, amount) in [(AccountKeyring::Alice, 5), (AccountKeyring::Bob, 3)] {
+		let _: H256 = api.call("author_submitExtrinsic", [transfer(sender, amount)]).await.unwrap();
+	}
+	let priority: Option<u64> =
+		api.call("author_poolMinPriority", EmptyParams::new()).await.unwrap();
+	assert_eq!(priority, None);
+
+	let _: H256 = api
+		.call("author_submitExtrinsic", [transfer(AccountKeyring::Charlie, 9)])
+		.await
+		.unwrap();
+	let priority: Option<u64> =
+		api.call("author_poolMinPriority", EmptyParams::new()).await.unwrap();
+	assert_eq!(priority, Some(3));
+}
+
+#[tokio::test]
+async fn author_should_return_runtime_spec() {
+	let api = TestSetup::into_rpc();
+
+	let spec: (u32, u32) = api.call("author_runtimeSpec", EmptyParams::new()).await.unwrap();
+	let version = substrate_test_runtime_client::runtime::VERSION;
+	assert_eq!(spec, (version.spec_version, version.transaction_version));
+}
+
+#[tokio::test]
+async fn author_should_return_genesis_hash() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	setup.import_block(vec![], true).await;
+
+	let hash: H256 = api.call("author_genesisHash", EmptyParams::new()).await.unwrap();
+	assert_eq!(hash, setup.client.genesis_hash());
+}
+
+#[tokio::test]
+async fn author_should_return_extrinsic_era() {
+	let api = TestSetup::into_rpc();
+
+	// The test runtime's transfers don't check any era, so never expire.
+	let xt = to_hex(&uxt(AccountKeyring::Alice, 0).encode(), true);
+	let era: EraInfo = api.call("author_extrinsicEra", [xt]).await.unwrap();
+	assert_eq!(era, EraInfo::Immortal);
+
+	let xt = to_hex(&Extrinsic::IncludeData(vec![1, 2, 3]).encode(), true);
+	assert_matches!(
+		api.call::<_, EraInfo>("author_extrinsicEra", [xt]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.message() == "Extrinsic is not signed"
+	);
+
+	assert_matches!(
+		api.call::<_, EraInfo>("author_extrinsicEra", ["0x00"]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.code() == 1001
+	);
+}
+
+#[tokio::test]
+async fn author_should_dry_run_extrinsic() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let best = setup.client.info().best_hash;
+	let bob_balance = || {
+		setup
+			.client
+			.runtime_api()
+			.balance_of(&generic::BlockId::hash(best), AccountKeyring::Bob.into())
+			.unwrap()
+	};
+	let balance_before = bob_balance();
+
+	let transfer = Transfer {
+		amount: 42,
+		nonce: 0,
+		from: AccountKeyring::Alice.into(),
+		to: AccountKeyring::Bob.into(),
+	}
+	.into_signed_tx();
+	let dry_run: dry_run::DryRunResult = api
+		.call("author_dryRunExtrinsic", [to_hex(&transfer.encode(), true)])
+		.await
+		.unwrap();
+	assert_eq!(dry_run.result.0, sp_runtime::ApplyExtrinsicResult::Ok(Ok(())).encode());
+	// The transfer is applied in a child block of the best block, so its event is recorded.
+	let events =
+		Vec::<frame_system::EventRecord<RuntimeEvent, H256>>::decode(&mut &dry_run.events.0[..])
+			.unwrap();
+	assert_eq!(
+		events,
+		vec![frame_system::EventRecord {
+			phase: frame_system::Phase::ApplyExtrinsic(0),
+			event: RuntimeEvent,
+			topics: vec![],
+		}]
+	);
+	assert_eq!(bob_balance(), balance_before);
+
+	// The transfer didn't use up its nonce, so it can still be included.
+	setup.import_block(vec![transfer], false).await;
+}
+
+#[tokio::test]
+async fn author_dry_run_requires_runtime_apis() {
+	let setup = TestSetup::default();
+	let api = Author { runtime_apis: None, ..setup.author() }.into_rpc();
+
+	let xt = to_hex(&uxt(AccountKeyring::Alice, 0).encode(), true);
+	assert_matches!(
+		api.call::<_, dry_run::DryRunResult>("author_dryRunExtrinsic", [xt]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.message().contains("runtime APIs")
+	);
+}
+
+#[tokio::test]
+async fn author_should_return_extrinsic_hash() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let xt = to_hex(&uxt(AccountKeyring::Alice, 0).encode(), true);
+
+	let hash: H256 = api.call("author_extrinsicHash", [xt.clone()]).await.unwrap();
+	// Nothing is submitted.
+	assert_eq!(setup.pool.status().ready, 0);
+
+	let submitted: H256 = api.call("author_submitExtrinsic", [xt]).await.unwrap();
+	assert_eq!(hash, submitted);
+
+	assert_matches!(
+		api.call::<_, H256>("author_extrinsicHash", ["0x00"]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.code() == 1001
+	);
+}
+
+#[tokio::test]
+async fn author_should_extract_call() {
+	let api = TestSetup::into_rpc();
+
+	// The test runtime's extrinsics are their own call.
+	let xt = uxt(AccountKeyring::Alice, 0);
+	let call: Bytes = api.call("author_extractCall", [to_hex(&xt.encode(), true)]).await.unwrap();
+	assert_eq!(Extrinsic::decode(&mut &call[..]), Ok(xt));
+
+	let xt = Extrinsic::IncludeData(vec![1, 2, 3]);
+	let call: Bytes = api.call("author_extractCall", [to_hex(&xt.encode(), true)]).await.unwrap();
+	assert_eq!(Extrinsic::decode(&mut &call[..]), Ok(xt));
+
+	assert_matches!(
+		api.call::<_, Bytes>("author_extractCall", ["0x00"]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.code() == 1001
+	);
+}
+
+#[tokio::test]
+async fn author_should_return_extrinsic_extensions() {
+	let api = TestSetup::into_rpc();
+
+	// The test runtime doesn't configure any signed extensions for transfers.
+	let xt = to_hex(&uxt(AccountKeyring::Alice, 0).encode(), true);
+	let extensions: Vec<(String, Bytes)> =
+		api.call("author_extrinsicExtensions", [xt]).await.unwrap();
+	assert_eq!(extensions, vec![]);
+
+	let xt = to_hex(&Extrinsic::IncludeData(vec![1, 2, 3]).encode(), true);
+	assert_matches!(
+		api.call::<_, Vec<(String, Bytes)>>("author_extrinsicExtensions", [xt]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.message() == "Extrinsic is not signed"
+	);
+
+	assert_matches!(
+		api.call::<_, Vec<(String, Bytes)>>("author_extrinsicExtensions", ["0x00"]).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.code() == 1001
+	);
+}
+
 #[tokio::test]
 async fn author_should_insert_key() {
 	let setup = TestSetup::default();
@@ -232,6 +1105,71 @@ async fn author_should_insert_key() {
 	);
 }
 
+#[tokio::test]
+async fn author_should_insert_derived_key() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let key_type = String::from_utf8(SR25519.0.to_vec()).expect("Keytype is a valid string");
+	let params = (key_type.clone(), "sr25519", DEV_PHRASE, Some("//stash/0"), Some("secret"));
+
+	let public: Bytes = api.call("author_insertKeyDerived", params).await.unwrap();
+
+	let keypair = sr25519::Pair::from_string(&format!("{}//stash/0///secret", DEV_PHRASE), None)
+		.expect("generates keypair");
+	assert_eq!(public.0, keypair.public().to_raw_vec());
+	let has_key: bool = api.call("author_hasKey", (public, key_type.clone())).await.unwrap();
+	assert!(has_key);
+
+	assert_matches!(
+		api.call::<_, Bytes>(
+			"author_insertKeyDerived",
+			(key_type.clone(), "rsa", DEV_PHRASE, None::<String>, None::<String>)
+		)
+		.await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.message().contains("Invalid crypto scheme")
+	);
+	assert_matches!(
+		api.call::<_, Bytes>(
+			"author_insertKeyDerived",
+			(key_type.clone(), "sr25519", "not a phrase", None::<String>, None::<String>)
+		)
+		.await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.message().contains("Invalid seed phrase")
+	);
+	for path in ["stash", "", "//stash///secret"] {
+		assert_matches!(
+			api.call::<_, Bytes>(
+				"author_insertKeyDerived",
+				(key_type.clone(), "sr25519", DEV_PHRASE, Some(path), None::<String>)
+			)
+			.await,
+			Err(RpcError::Call(CallError::Custom(err))) if err.message().contains("Invalid derivation path")
+		);
+	}
+}
+
+#[tokio::test]
+async fn author_should_report_keystore_writability() {
+	let api = TestSetup::into_rpc();
+	let writable: bool = api.call("author_keystoreWritable", EmptyParams::new()).await.unwrap();
+	assert!(writable);
+
+	let setup = TestSetup { keystore: Arc::new(KeyStore::new_read_only()), ..Default::default() };
+	let api = setup.author().into_rpc();
+	let writable: bool = api.call("author_keystoreWritable", EmptyParams::new()).await.unwrap();
+	assert!(!writable);
+
+	// Inserting a key fails as announced.
+	let suri = "//Alice";
+	let keypair = ed25519::Pair::from_string(suri, None).expect("generates keypair");
+	let params: (String, String, Bytes) = (
+		String::from_utf8(ED25519.0.to_vec()).expect("Keytype is a valid string"),
+		suri.to_string(),
+		keypair.public().0.to_vec().into(),
+	);
+	assert!(api.call::<_, ()>("author_insertKey", params).await.is_err());
+}
+
 #[tokio::test]
 async fn author_should_rotate_keys() {
 	let setup = TestSetup::default();
@@ -248,6 +1186,67 @@ async fn author_should_rotate_keys() {
 		.contains(&CryptoTypePublicPair(sr25519::CRYPTO_ID, session_keys.sr25519.to_raw_vec())));
 }
 
+#[tokio::test]
+async fn author_should_report_authoring() {
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+	let is_authoring =
+		|| async { api.call::<_, bool>("author_isAuthoring", EmptyParams::new()).await.unwrap() };
+
+	let _: Bytes = api.call("author_rotateKeys", EmptyParams::new()).await.unwrap();
+	assert!(!is_authoring().await);
+
+	// The test runtime uses its authorities, Alice among them, as validators.
+	let alice = AccountKeyring::Alice.public().to_raw_vec();
+	SyncCryptoStore::insert_unknown(&*setup.keystore, key_types::BABE, "//Alice", &alice).unwrap();
+	assert!(is_authoring().await);
+
+	// A node without the keys is not authoring.
+	let author = Author { keystore: Arc::new(KeyStore::new()), ..setup.author() };
+	let is_authoring: bool =
+		author.into_rpc().call("author_isAuthoring", EmptyParams::new()).await.unwrap();
+	assert!(!is_authoring);
+
+	let author = Author { runtime_apis: None, ..setup.author() };
+	assert_matches!(
+		author.into_rpc().call::<_, bool>("author_isAuthoring", EmptyParams::new()).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.message().contains("runtime APIs")
+	);
+}
+
+#[test]
+fn session_keys_api_is_required() {
+	let version = substrate_test_runtime_client::runtime::VERSION;
+	assert!(check_session_keys_api::<Block>(&version).is_ok());
+
+	// A runtime lacking the session keys API.
+	let apis = version
+		.apis
+		.iter()
+		.filter(|(id, _)| *id != <dyn sp_session::SessionKeys<Block>>::ID)
+		.cloned()
+		.collect::<Vec<_>>();
+	let version = RuntimeVersion { apis: apis.into(), ..version };
+	assert_matches!(
+		check_session_keys_api::<Block>(&version),
+		Err(Error::SessionKeysApiUnavailable)
+	);
+}
+
+#[tokio::test]
+async fn author_should_return_required_key_types() {
+	let api = TestSetup::into_rpc();
+
+	let key_types: Vec<String> =
+		api.call("author_requiredKeyTypes", EmptyParams::new()).await.unwrap();
+
+	let expected = [ED25519, SR25519, ECDSA]
+		.iter()
+		.map(|key_type| String::from_utf8(key_type.0.to_vec()).unwrap())
+		.collect::<Vec<_>>();
+	assert_eq!(key_types, expected);
+}
+
 #[tokio::test]
 async fn author_has_session_keys() {
 	// Setup
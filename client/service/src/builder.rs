@@ -688,6 +688,8 @@ where
 		keystore,
 		deny_unsafe,
 		task_executor.clone(),
+		sc_rpc::author::RECENTLY_FINALIZED_CAPACITY,
+		None,
 	)
 	.into_rpc();
 
@@ -35,7 +35,8 @@ pub use sp_runtime::transaction_validity::{
 };
 
 /// Transaction pool status.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PoolStatus {
 	/// Number of transactions in the ready queue.
 	pub ready: usize,
@@ -47,6 +48,20 @@ pub struct PoolStatus {
 	pub future_bytes: usize,
 }
 
+/// Transaction pool limits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolLimits {
+	/// Maximum number of transactions in the ready queue.
+	pub ready: usize,
+	/// Maximum sum of bytes of ready transaction encodings.
+	pub ready_bytes: usize,
+	/// Maximum number of transactions in the future queue.
+	pub future: usize,
+	/// Maximum sum of bytes of future transaction encodings.
+	pub future_bytes: usize,
+}
+
 impl PoolStatus {
 	/// Returns true if the are no transactions in the pool.
 	pub fn is_empty(&self) -> bool {
@@ -220,6 +235,17 @@ pub trait TransactionPool: Send + Sync {
 		xt: TransactionFor<Self>,
 	) -> PoolFuture<Pin<Box<TransactionStatusStreamFor<Self>>>, Self::Error>;
 
+	/// Returns a future that imports one unverified local transaction to the pool, with its
+	/// validated priority replaced by `priority`.
+	///
+	/// The override holds until the transaction is revalidated.
+	fn submit_local_with_priority(
+		&self,
+		at: &BlockId<Self::Block>,
+		xt: TransactionFor<Self>,
+		priority: TransactionPriority,
+	) -> PoolFuture<TxHash<Self>, Self::Error>;
+
 	// *** Block production / Networking
 	/// Get an iterator for ready transactions ordered by priority.
 	///
@@ -247,6 +273,9 @@ pub trait TransactionPool: Send + Sync {
 	/// Returns pool status.
 	fn status(&self) -> PoolStatus;
 
+	/// Returns the limits the pool was configured with.
+	fn limits(&self) -> PoolLimits;
+
 	// *** logging / RPC / networking
 	/// Return an event stream of transactions imported to the pool.
 	fn import_notification_stream(&self) -> ImportNotificationStream<TxHash<Self>>;
@@ -308,6 +337,12 @@ pub enum ChainEvent<B: BlockT> {
 pub trait MaintainedTransactionPool: TransactionPool {
 	/// Perform maintenance
 	async fn maintain(&self, event: ChainEvent<Self::Block>);
+
+	/// Immediately revalidate all ready transactions against the state at block `at`.
+	///
+	/// Transactions that turn out to be invalid are removed from the pool. Returns the number
+	/// of removed transactions.
+	async fn revalidate(&self, at: <Self::Block as BlockT>::Hash) -> usize;
 }
 
 /// Transaction pool interface for submitting local transactions that exposes a
@@ -25,7 +25,8 @@ use sp_runtime::{
 	generic::BlockId,
 	traits::{self, Block as BlockT, SaturatedConversion},
 	transaction_validity::{
-		TransactionSource, TransactionTag as Tag, TransactionValidity, TransactionValidityError,
+		TransactionPriority, TransactionSource, TransactionTag as Tag, TransactionValidity,
+		TransactionValidityError,
 	},
 };
 use std::time::Instant;
@@ -210,6 +211,30 @@ impl<B: ChainApi> Pool<B> {
 		self.validated_pool.submit_and_watch(tx)
 	}
 
+	/// Imports one unverified local extrinsic to the pool, overriding its validated priority.
+	pub async fn submit_local_with_priority(
+		&self,
+		at: &BlockId<B::Block>,
+		xt: ExtrinsicFor<B>,
+		priority: TransactionPriority,
+	) -> Result<ExtrinsicHash<B>, B::Error> {
+		let block_number = self.resolve_block_number(at)?;
+		let (_, mut tx) = self
+			.verify_one(
+				at,
+				block_number,
+				TransactionSource::Local,
+				xt,
+				CheckBannedBeforeVerify::Yes,
+			)
+			.await;
+		if let ValidatedTransaction::Valid(ref mut tx) = tx {
+			tx.priority = priority;
+		}
+		let res = self.validated_pool.submit(std::iter::once(tx)).pop();
+		res.expect("One extrinsic passed; one result returned; qed")
+	}
+
 	/// Resubmit some transaction that were validated elsewhere.
 	pub fn resubmit(
 		&self,
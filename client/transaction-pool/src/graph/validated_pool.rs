@@ -573,6 +573,11 @@ impl<B: ChainApi> ValidatedPool<B> {
 		&self.api
 	}
 
+	/// Get the options the pool was created with.
+	pub fn options(&self) -> &Options {
+		&self.options
+	}
+
 	/// Return an event stream of notifications for when transactions are imported to the pool.
 	///
 	/// Consumers of this stream should use the `ready` method to actually get the
@@ -52,8 +52,8 @@ use std::{
 use graph::{ExtrinsicHash, IsValidator};
 use sc_transaction_pool_api::{
 	error::Error as TxPoolError, ChainEvent, ImportNotificationStream, MaintainedTransactionPool,
-	PoolFuture, PoolStatus, ReadyTransactions, TransactionFor, TransactionPool, TransactionSource,
-	TransactionStatusStreamFor, TxHash,
+	PoolFuture, PoolLimits, PoolStatus, ReadyTransactions, TransactionFor, TransactionPool,
+	TransactionPriority, TransactionSource, TransactionStatusStreamFor, TxHash,
 };
 use sp_core::traits::SpawnEssentialNamed;
 use sp_runtime::{
@@ -309,6 +309,20 @@ where
 		.boxed()
 	}
 
+	fn submit_local_with_priority(
+		&self,
+		at: &BlockId<Self::Block>,
+		xt: TransactionFor<Self>,
+		priority: TransactionPriority,
+	) -> PoolFuture<TxHash<Self>, Self::Error> {
+		let pool = self.pool.clone();
+		let at = *at;
+
+		self.metrics.report(|metrics| metrics.submitted_transactions.inc());
+
+		async move { pool.submit_local_with_priority(&at, xt, priority).await }.boxed()
+	}
+
 	fn remove_invalid(&self, hashes: &[TxHash<Self>]) -> Vec<Arc<Self::InPoolTransaction>> {
 		let removed = self.pool.validated_pool().remove_invalid(hashes);
 		self.metrics
@@ -320,6 +334,16 @@ where
 		self.pool.validated_pool().status()
 	}
 
+	fn limits(&self) -> PoolLimits {
+		let options = self.pool.validated_pool().options();
+		PoolLimits {
+			ready: options.ready.count,
+			ready_bytes: options.ready.total_bytes,
+			future: options.future.count,
+			future_bytes: options.future.total_bytes,
+		}
+	}
+
 	fn import_notification_stream(&self) -> ImportNotificationStream<TxHash<Self>> {
 		self.pool.validated_pool().import_notification_stream()
 	}
@@ -775,6 +799,23 @@ where
 			}
 		}
 	}
+
+	async fn revalidate(&self, at: Block::Hash) -> usize {
+		let number = match self.api.block_id_to_number(&BlockId::Hash(at)) {
+			Ok(Some(number)) => number,
+			Ok(None) => {
+				log::debug!(target: "txpool", "Could not find block {:?} to revalidate at.", at);
+				return 0
+			},
+			Err(e) => {
+				log::debug!(target: "txpool", "Error retrieving number of block {:?}: {}", at, e);
+				return 0
+			},
+		};
+
+		let hashes = self.pool.validated_pool().ready().map(|tx| tx.hash).collect::<Vec<_>>();
+		revalidation::batch_revalidate(self.pool.clone(), self.api.clone(), number, hashes).await
+	}
 }
 
 /// Inform the transaction pool about imported and finalized blocks.
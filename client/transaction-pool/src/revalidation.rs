@@ -62,12 +62,14 @@ impl<Api: ChainApi> Unpin for RevalidationWorker<Api> {}
 ///
 /// Each transaction is validated  against chain, and invalid are
 /// removed from the `pool`, while valid are resubmitted.
-async fn batch_revalidate<Api: ChainApi>(
+///
+/// Returns the number of transactions removed as invalid.
+pub(crate) async fn batch_revalidate<Api: ChainApi>(
 	pool: Arc<Pool<Api>>,
 	api: Arc<Api>,
 	at: NumberFor<Api>,
 	batch: impl IntoIterator<Item = ExtrinsicHash<Api>>,
-) {
+) -> usize {
 	let mut invalid_hashes = Vec::new();
 	let mut revalidated = HashMap::new();
 
@@ -115,10 +117,12 @@ async fn batch_revalidate<Api: ChainApi>(
 		}
 	}
 
-	pool.validated_pool().remove_invalid(&invalid_hashes);
+	let removed = pool.validated_pool().remove_invalid(&invalid_hashes).len();
 	if revalidated.len() > 0 {
 		pool.resubmit(revalidated);
 	}
+
+	removed
 }
 
 impl<Api: ChainApi> RevalidationWorker<Api> {
@@ -332,7 +336,7 @@ where
 		} else {
 			let pool = self.pool.clone();
 			let api = self.api.clone();
-			batch_revalidate(pool, api, at, transactions).await
+			batch_revalidate(pool, api, at, transactions).await;
 		}
 	}
 }
@@ -0,0 +1,40 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for bags list pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait BagsListApi<AccountId, Score> where
+		AccountId: Codec,
+		Score: Codec,
+	{
+		/// The upper threshold and number of nodes of each bag, from the heaviest bag down,
+		/// counting at most `limit` nodes after `start`, or from the head of the list if `None`.
+		///
+		/// Also returns the last node counted if the limit was reached, from which the next page
+		/// starts. Returns `None` if `start` is not in the list.
+		fn bag_distribution(
+			start: Option<AccountId>,
+			limit: u32,
+		) -> Option<(Vec<(Score, u32)>, Option<AccountId>)>;
+	}
+}
@@ -0,0 +1,130 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the bags list pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_bags_list_rpc_runtime_api::BagsListApi as BagsListRuntimeApi;
+
+/// The maximum number of nodes counted by a single call to `bagsList_bagDistribution`.
+pub const MAX_BAG_DISTRIBUTION_PAGE: u32 = 1024;
+
+#[rpc(client, server)]
+pub trait BagsListApi<BlockHash, AccountId, Score> {
+	/// The upper threshold and number of nodes of each bag, from the heaviest bag down.
+	///
+	/// At most `limit` nodes are counted, [`MAX_BAG_DISTRIBUTION_PAGE`] if `None`, starting after
+	/// `start` or from the head of the list. Also returns the last node counted if the limit was
+	/// reached, to pass as `start` to fetch the next page. A bag spanning several pages appears
+	/// in each of them with a partial count.
+	#[method(name = "bagsList_bagDistribution")]
+	fn bag_distribution(
+		&self,
+		start: Option<AccountId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Vec<(Score, u32)>, Option<AccountId>)>;
+}
+
+/// Provides RPC methods to inspect the occupancy of the bags list.
+pub struct BagsList<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> BagsList<C, P> {
+	/// Creates a new instance of the BagsList Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The requested page is invalid.
+	InvalidPage,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidPage => 2,
+		}
+	}
+}
+
+impl<C, Block, AccountId, Score> BagsListApiServer<<Block as BlockT>::Hash, AccountId, Score>
+	for BagsList<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: BagsListRuntimeApi<Block, AccountId, Score>,
+	AccountId: Codec + Send + Sync + 'static,
+	Score: Codec + Send + Sync + 'static,
+{
+	fn bag_distribution(
+		&self,
+		start: Option<AccountId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<(Vec<(Score, u32)>, Option<AccountId>)> {
+		let limit = limit.unwrap_or(MAX_BAG_DISTRIBUTION_PAGE);
+		if limit > MAX_BAG_DISTRIBUTION_PAGE {
+			return Err(CallError::Custom(ErrorObject::owned(
+				Error::InvalidPage.into(),
+				format!("The limit must be at most {}.", MAX_BAG_DISTRIBUTION_PAGE),
+				None::<()>,
+			))
+			.into())
+		}
+
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.bag_distribution(&at, start, limit)
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to query bag distribution.",
+					Some(e.to_string()),
+				))
+			})?
+			.ok_or_else(|| {
+				CallError::Custom(ErrorObject::owned(
+					Error::InvalidPage.into(),
+					"The start of the page is not in the bags list.",
+					None::<()>,
+				))
+				.into()
+			})
+	}
+}
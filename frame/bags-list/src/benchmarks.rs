@@ -23,7 +23,7 @@ use frame_benchmarking::{account, whitelist_account, whitelisted_caller};
 use frame_election_provider_support::ScoreProvider;
 use frame_support::{assert_ok, traits::Get};
 use frame_system::RawOrigin as SystemOrigin;
-use sp_runtime::traits::One;
+use sp_runtime::traits::{Bounded, One};
 
 frame_benchmarking::benchmarks_instance_pallet! {
 	rebag_non_terminal {
@@ -186,6 +186,52 @@ frame_benchmarking::benchmarks_instance_pallet! {
 		)
 	}
 
+	rebag_and_report {
+		// The most expensive case for `rebag_and_report`:
+		//
+		// - The node to be rebagged, _R_, is a terminal node that moves to an empty bag (see
+		//   `rebag_terminal`).
+		// - _R_ ends up as the only node of its new bag, and its neighbours are in the lightest and
+		//   the heaviest bag. Thus every other bag is read while looking them up.
+
+		// clear any pre-existing storage.
+		// NOTE: safe to call outside block production
+		List::<T, I>::unsafe_clear();
+
+		// define our origin and destination thresholds.
+		let origin_bag_thresh = T::BagThresholds::get()[0];
+		let dest_bag_thresh = T::BagThresholds::get()[1];
+
+		// seed items in the origin bag.
+		let lighter: T::AccountId = account("lighter", 0, 0);
+		assert_ok!(List::<T, _>::insert(lighter.clone(), origin_bag_thresh));
+
+		let origin_tail: T::AccountId = account("origin_tail", 0, 0); // the node we rebag (_R_)
+		assert_ok!(List::<T, _>::insert(origin_tail.clone(), origin_bag_thresh));
+
+		// seed an item in the heaviest bag.
+		let heavier: T::AccountId = account("heavier", 0, 0);
+		assert_ok!(List::<T, _>::insert(heavier.clone(), T::Score::max_value()));
+
+		let origin_tail_lookup = T::Lookup::unlookup(origin_tail.clone());
+
+		let caller = whitelisted_caller();
+		// update the weight of `origin_tail` to guarantee it will be rebagged into the destination.
+		T::ScoreProvider::set_score_of(&origin_tail, dest_bag_thresh);
+	}: _(SystemOrigin::Signed(caller), origin_tail_lookup.clone())
+	verify {
+		// check the bags have updated as expected.
+		assert_eq!(
+			List::<T, _>::get_bags(),
+			vec![
+				(origin_bag_thresh, vec![lighter.clone()]),
+				(dest_bag_thresh, vec![origin_tail.clone()]),
+				(T::Score::max_value(), vec![heavier.clone()]),
+			]
+		);
+		assert_eq!(List::<T, _>::neighbours(&origin_tail), Some((Some(heavier), Some(lighter))));
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::ExtBuilder::default().skip_genesis_ids().build(),
@@ -55,6 +55,7 @@
 
 use codec::FullCodec;
 use frame_election_provider_support::{ScoreProvider, SortedListProvider};
+use frame_support::traits::Get;
 use frame_system::ensure_signed;
 use sp_runtime::traits::{AtLeast32BitUnsigned, Bounded, StaticLookup};
 use sp_std::prelude::*;
@@ -70,7 +71,7 @@ pub mod mock;
 mod tests;
 pub mod weights;
 
-pub use list::{notional_bag_for, Bag, List, ListError, Node};
+pub use list::{notional_bag_for, Bag, ClearCursor, List, ListError, Node};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -92,14 +93,34 @@ macro_rules! log {
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+/// Something that tells whether an id is expected to leave the list, e.g. because the pallet that
+/// is the source of truth for the list no longer tracks it.
+///
+/// The removal itself is never refused, as the source of truth has usually already forgotten the
+/// id by the time it asks for its removal, and keeping it would leave the two out of sync.
+pub trait OnRemoveGuard<AccountId> {
+	/// Whether `id` may be removed from the list.
+	fn can_remove(id: &AccountId) -> bool;
+}
+
+impl<AccountId> OnRemoveGuard<AccountId> for () {
+	fn can_remove(_: &AccountId) -> bool {
+		true
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(crate) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::config]
@@ -160,6 +181,41 @@ pub mod pallet {
 		#[pallet::constant]
 		type BagThresholds: Get<&'static [Self::Score]>;
 
+		/// An optional upper bound on the score an id can be updated to.
+		///
+		/// A score far above the highest bag threshold is more likely the result of an overflow
+		/// in whatever computes it than a genuine value. If set, [`SortedListProvider::on_insert`]
+		/// and [`SortedListProvider::on_update`] log any score above this bound and clamp it to the
+		/// bound. If `None`, no check is performed.
+		#[pallet::constant]
+		type MaxReasonableWeight: Get<Option<Self::Score>>;
+
+		/// The origin that can force an id into any bag, regardless of its score.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Checked when an id is removed through [`SortedListProvider::on_remove`]. Removing an id
+		/// it objects to is a defensive failure, reported but carried out. Use `()` to allow all
+		/// removals.
+		type OnRemoveGuard: OnRemoveGuard<Self::AccountId>;
+
+		/// Auxiliary data stored alongside the score of each id, e.g. a reputation value.
+		///
+		/// It has no effect on the position of an id in the list. Use `()` if not needed, which
+		/// takes no space in storage.
+		///
+		/// # Migration
+		///
+		/// The metadata is part of every stored node, so changing this type requires migrating all
+		/// of `ListNodes`, as [`migrations::AddMetadata`] does when introducing it. Nodes that fail
+		/// to decode are reported by `try_state`.
+		type NodeMetadata: Clone
+			+ Default
+			+ PartialEq
+			+ sp_std::fmt::Debug
+			+ TypeInfo
+			+ FullCodec
+			+ MaxEncodedLen;
+
 		/// The type used to dictate a node position relative to other nodes.
 		type Score: Clone
 			+ Default
@@ -197,6 +253,13 @@ pub mod pallet {
 		Rebagged { who: T::AccountId, from: T::Score, to: T::Score },
 		/// Updated the score of some account to the given amount.
 		ScoreUpdated { who: T::AccountId, new_score: T::Score },
+		/// Moved an account to another bag through [`Pallet::rebag_and_report`]. `heavier` and
+		/// `lighter` are its neighbours in the list after the move.
+		RebaggedWithNeighbors {
+			who: T::AccountId,
+			heavier: Option<T::AccountId>,
+			lighter: Option<T::AccountId>,
+		},
 	}
 
 	#[pallet::error]
@@ -224,6 +287,9 @@ pub mod pallet {
 		/// `ScoreProvider`.
 		///
 		/// If `dislocated` does not exists, it returns an error.
+		///
+		/// The [`Event::Rebagged`] event is only emitted if `dislocated` actually moved to another
+		/// bag. The fee is charged either way.
 		#[pallet::weight(T::WeightInfo::rebag_non_terminal().max(T::WeightInfo::rebag_terminal()))]
 		pub fn rebag(origin: OriginFor<T>, dislocated: AccountIdLookupOf<T>) -> DispatchResult {
 			ensure_signed(origin)?;
@@ -253,6 +319,56 @@ pub mod pallet {
 				.map_err::<Error<T, I>, _>(Into::into)
 				.map_err::<DispatchError, _>(Into::into)
 		}
+
+		/// Force `who` into the bag with the upper threshold `bag`, regardless of its score.
+		///
+		/// `who` is moved to the tail of that bag, and its stored score is set to the lowest score
+		/// of the bag so that the list stays consistent. The next rebag of `who` moves it back
+		/// according to its actual score.
+		///
+		/// The dispatch origin for this call must be [`Config::ForceOrigin`].
+		#[pallet::weight(T::WeightInfo::rebag_non_terminal().max(T::WeightInfo::rebag_terminal()))]
+		pub fn force_rebag_into(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			bag: T::Score,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let score = list::notional_bag_lower_bound::<T, I>(bag)
+				.ok_or(Error::<T, I>::List(ListError::InvalidBag))?;
+			Pallet::<T, I>::do_rebag(&who, score).map_err::<Error<T, I>, _>(Into::into)?;
+			Ok(())
+		}
+
+		/// Same as [`Pallet::rebag`], but also reports the new neighbours of `dislocated`.
+		///
+		/// If `dislocated` moved to another bag, [`Event::RebaggedWithNeighbors`] is emitted
+		/// alongside [`Event::Rebagged`], with the ids directly in front of and behind
+		/// `dislocated` after the move. The fee is charged either way.
+		#[pallet::weight(T::WeightInfo::rebag_and_report())]
+		pub fn rebag_and_report(
+			origin: OriginFor<T>,
+			dislocated: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let dislocated = T::Lookup::lookup(dislocated)?;
+			let current_score = T::ScoreProvider::score(&dislocated);
+			let maybe_movement = Pallet::<T, I>::do_rebag(&dislocated, current_score)
+				.map_err::<Error<T, I>, _>(Into::into)?;
+			if maybe_movement.is_none() {
+				return Ok(())
+			}
+
+			let (heavier, lighter) = List::<T, I>::neighbours(&dislocated)
+				.ok_or(Error::<T, I>::List(ListError::NodeNotFound))?;
+			Self::deposit_event(Event::<T, I>::RebaggedWithNeighbors {
+				who: dislocated,
+				heavier,
+				lighter,
+			});
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -290,6 +406,68 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(maybe_movement)
 	}
 
+	/// Insert `id` with the given `score` and `metadata`.
+	///
+	/// Same as [`SortedListProvider::on_insert`], which inserts with the default metadata.
+	pub fn insert_with_metadata(
+		id: T::AccountId,
+		score: T::Score,
+		metadata: T::NodeMetadata,
+	) -> Result<(), ListError> {
+		let score = Self::clamp_score(&id, score);
+		List::<T, I>::insert_with_metadata(id, score, metadata)
+	}
+
+	/// Clamp `score` to [`Config::MaxReasonableWeight`], logging if it was above it.
+	fn clamp_score(id: &T::AccountId, score: T::Score) -> T::Score {
+		match T::MaxReasonableWeight::get() {
+			Some(max) if score > max => {
+				log!(
+					warn,
+					"clamping score {:?} of {:?} to the reasonable maximum {:?}",
+					score,
+					id,
+					max,
+				);
+				max
+			},
+			_ => score,
+		}
+	}
+
+	/// Update the metadata stored alongside `id`, without changing its position.
+	pub fn update_metadata(id: &T::AccountId, metadata: T::NodeMetadata) -> Result<(), ListError> {
+		List::<T, I>::update_metadata(id, metadata)
+	}
+
+	/// Same as [`SortedListProvider::iter`], but also yields the metadata of each id.
+	pub fn iter_with_metadata() -> Box<dyn Iterator<Item = (T::AccountId, T::NodeMetadata)>> {
+		Box::new(List::<T, I>::iter().map(|n| (n.id().clone(), n.metadata().clone())))
+	}
+
+	/// The upper threshold and number of nodes of each bag, from the heaviest bag down, counting
+	/// at most `limit` nodes after `start`, or from the head of the list if `None`.
+	///
+	/// Also returns the last node counted if the limit was reached, to pass as the `start` of the
+	/// next page. Meant to be called off-chain, e.g. through the `BagsListApi` runtime API.
+	pub fn bag_distribution(
+		start: Option<&T::AccountId>,
+		limit: u32,
+	) -> Result<(Vec<(T::Score, u32)>, Option<T::AccountId>), ListError> {
+		List::<T, I>::bag_distribution(start, limit)
+	}
+
+	/// Remove at most `limit` bags and nodes of the list from storage, resuming from `cursor`.
+	///
+	/// Pass `None` on the first call, then the returned cursor until `None` is returned, at which
+	/// point the list is empty. Unlike [`SortedListProvider::unsafe_clear`], this allows clearing
+	/// a large list over several blocks, e.g. in a multi-block migration. Keys removed earlier in
+	/// the same block still count towards the limit, so call this at most once per block. No ids
+	/// must be inserted until the list is empty.
+	pub fn clear(limit: u32, cursor: Option<ClearCursor>) -> Option<ClearCursor> {
+		List::<T, I>::clear(limit, cursor)
+	}
+
 	/// Equivalent to `ListBags::get`, but public. Useful for tests in outside of this crate.
 	#[cfg(feature = "std")]
 	pub fn list_bags_get(score: T::Score) -> Option<list::Bag<T, I>> {
@@ -321,6 +499,7 @@ impl<T: Config<I>, I: 'static> SortedListProvider<T::AccountId> for Pallet<T, I>
 	}
 
 	fn on_insert(id: T::AccountId, score: T::Score) -> Result<(), ListError> {
+		let score = Pallet::<T, I>::clamp_score(&id, score);
 		List::<T, I>::insert(id, score)
 	}
 
@@ -328,12 +507,26 @@ impl<T: Config<I>, I: 'static> SortedListProvider<T::AccountId> for Pallet<T, I>
 		List::<T, I>::get_score(id)
 	}
 
+	fn total_score() -> T::Score {
+		List::<T, I>::total_score()
+	}
+
+	fn rank_of(id: &T::AccountId) -> Option<(u32, u32)> {
+		List::<T, I>::rank_of(id)
+	}
+
 	fn on_update(id: &T::AccountId, new_score: T::Score) -> Result<(), ListError> {
+		let new_score = Pallet::<T, I>::clamp_score(id, new_score);
 		Pallet::<T, I>::do_rebag(id, new_score).map(|_| ())
 	}
 
 	fn on_remove(id: &T::AccountId) -> Result<(), ListError> {
-		List::<T, I>::remove(id)
+		List::<T, I>::remove(id)?;
+		if !T::OnRemoveGuard::can_remove(id) {
+			log!(warn, "removed {:?}, which is still expected to be in the list", id);
+			frame_support::defensive!("removed an id still expected to be in the list");
+		}
+		Ok(())
 	}
 
 	fn unsafe_regenerate(
@@ -33,7 +33,7 @@ use frame_support::{
 	DefaultNoBound, PalletError,
 };
 use scale_info::TypeInfo;
-use sp_runtime::traits::{Bounded, Zero};
+use sp_runtime::traits::{Bounded, One, Saturating, Zero};
 use sp_std::{
 	boxed::Box,
 	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
@@ -52,11 +52,38 @@ pub enum ListError {
 	NotInSameBag,
 	/// Given node id was not found.
 	NodeNotFound,
+	/// The given score is not the upper threshold of any bag.
+	InvalidBag,
 }
 
 #[cfg(test)]
 mod tests;
 
+/// Counters of the bag and node storage reads, used in tests to check the number of reads that
+/// the weight of each operation assumes.
+#[cfg(test)]
+pub(crate) mod reads {
+	use std::cell::Cell;
+
+	thread_local! {
+		static BAGS: Cell<u32> = Cell::new(0);
+		static NODES: Cell<u32> = Cell::new(0);
+	}
+
+	pub(crate) fn bag() {
+		BAGS.with(|c| c.set(c.get() + 1));
+	}
+
+	pub(crate) fn node() {
+		NODES.with(|c| c.set(c.get() + 1));
+	}
+
+	/// Reset the counters, returning the `(bag, node)` reads since the last reset.
+	pub(crate) fn take() -> (u32, u32) {
+		(BAGS.with(|c| c.take()), NODES.with(|c| c.take()))
+	}
+}
+
 /// Given a certain score, to which bag does it belong to?
 ///
 /// Bags are identified by their upper threshold; the value returned by this function is guaranteed
@@ -70,6 +97,21 @@ pub fn notional_bag_for<T: Config<I>, I: 'static>(score: T::Score) -> T::Score {
 	thresholds.get(idx).copied().unwrap_or_else(T::Score::max_value)
 }
 
+/// The lowest score that falls into the bag with the upper threshold `bag_upper`.
+///
+/// Returns `None` if `bag_upper` is not the upper threshold of any bag. As in [`notional_bag_for`],
+/// `T::Score::max_value()` is always considered to be the upper threshold of the last bag.
+pub fn notional_bag_lower_bound<T: Config<I>, I: 'static>(bag_upper: T::Score) -> Option<T::Score> {
+	let thresholds = T::BagThresholds::get();
+	let idx = thresholds.partition_point(|&threshold| bag_upper > threshold);
+	match thresholds.get(idx) {
+		Some(&threshold) if threshold == bag_upper => (),
+		None if bag_upper == T::Score::max_value() => (),
+		_ => return None,
+	}
+	Some(idx.checked_sub(1).map_or_else(Zero::zero, |prev| thresholds[prev] + One::one()))
+}
+
 /// The **ONLY** entry point of this module. All operations to the bags-list should happen through
 /// this interface. It is forbidden to access other module members directly.
 //
@@ -86,18 +128,53 @@ pub fn notional_bag_for<T: Config<I>, I: 'static>(score: T::Score) -> T::Score {
 // satisfies both the desire for fairness and the requirement for efficiency.
 pub struct List<T: Config<I>, I: 'static = ()>(PhantomData<(T, I)>);
 
+/// Where a [`List::clear`] spanning several calls resumes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClearCursor {
+	/// Within the bags, from the given storage cursor.
+	Bags(Vec<u8>),
+	/// Within the nodes, from the given storage cursor, or their start if `None`.
+	Nodes(Option<Vec<u8>>),
+}
+
 impl<T: Config<I>, I: 'static> List<T, I> {
 	/// Remove all data associated with the list from storage.
 	///
 	/// ## WARNING
 	///
 	/// this function should generally not be used in production as it could lead to a very large
-	/// number of storage accesses.
+	/// number of storage accesses. Use [`List::clear`] to spread the removal over several blocks.
 	pub(crate) fn unsafe_clear() {
-		#[allow(deprecated)]
-		crate::ListBags::<T, I>::remove_all(None);
-		#[allow(deprecated)]
-		crate::ListNodes::<T, I>::remove_all();
+		let _ = crate::ListBags::<T, I>::clear(u32::MAX, None);
+		// this also resets the node counter.
+		let _ = crate::ListNodes::<T, I>::clear(u32::MAX, None);
+	}
+
+	/// Remove at most `limit` bags and nodes of the list from storage, resuming from `cursor`.
+	///
+	/// Pass `None` on the first call, then the returned cursor until `None` is returned, at which
+	/// point the list is empty. No ids must be inserted in between. Keys removed earlier in the
+	/// same block still count towards the limit, so call this at most once per block.
+	pub(crate) fn clear(limit: u32, cursor: Option<ClearCursor>) -> Option<ClearCursor> {
+		let (limit, nodes_cursor) = match cursor {
+			None | Some(ClearCursor::Bags(_)) => {
+				let bags_cursor = match &cursor {
+					Some(ClearCursor::Bags(bags_cursor)) => Some(bags_cursor.as_slice()),
+					_ => None,
+				};
+				let result = crate::ListBags::<T, I>::clear(limit, bags_cursor);
+				if let Some(bags_cursor) = result.maybe_cursor {
+					return Some(ClearCursor::Bags(bags_cursor))
+				}
+				(limit.saturating_sub(result.unique), None)
+			},
+			Some(ClearCursor::Nodes(nodes_cursor)) => (limit, nodes_cursor),
+		};
+
+		// this also resets the node counter once the last node is removed.
+		crate::ListNodes::<T, I>::clear(limit, nodes_cursor.as_deref())
+			.maybe_cursor
+			.map(|nodes_cursor| ClearCursor::Nodes(Some(nodes_cursor)))
 	}
 
 	/// Regenerate all of the data from the given ids.
@@ -228,6 +305,8 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 
 	/// Returns `true` if the list contains `id`, otherwise returns `false`.
 	pub(crate) fn contains(id: &T::AccountId) -> bool {
+		#[cfg(test)]
+		reads::node();
 		crate::ListNodes::<T, I>::contains_key(id)
 	}
 
@@ -236,6 +315,25 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		Node::<T, I>::get(id).map(|node| node.score()).ok_or(ListError::NodeNotFound)
 	}
 
+	/// The sum of the scores of all nodes in the list, saturating at the maximum score.
+	///
+	/// This walks all bags, and is thus as expensive as a full iteration.
+	pub(crate) fn total_score() -> T::Score {
+		Self::iter().fold(Zero::zero(), |total: T::Score, node| total.saturating_add(node.score()))
+	}
+
+	/// The position of `id` in the list, counting from the heaviest node, and the number of nodes
+	/// in the list. `None` if `id` is not in the list.
+	///
+	/// This walks all nodes in heavier bags, and the nodes ahead of `id` in its own bag.
+	pub(crate) fn rank_of(id: &T::AccountId) -> Option<(u32, u32)> {
+		if !Self::contains(id) {
+			return None
+		}
+		let position = Self::iter().position(|node| node.id == *id)?;
+		Some((position as u32, crate::ListNodes::<T, I>::count()))
+	}
+
 	/// Iterate over all nodes in all bags in the list.
 	///
 	/// Full iteration can be expensive; it's recommended to limit the number of items with
@@ -262,6 +360,35 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		iter.filter_map(Bag::get).flat_map(|bag| bag.iter())
 	}
 
+	/// The upper threshold and number of nodes of each bag, from the heaviest bag down, counting
+	/// at most `limit` nodes after `start`, or from the head of the list if `None`.
+	///
+	/// Also returns the last node counted if the limit was reached, from which the next page
+	/// starts. A bag spanning several pages appears in each of them with a partial count.
+	pub(crate) fn bag_distribution(
+		start: Option<&T::AccountId>,
+		limit: u32,
+	) -> Result<(Vec<(T::Score, u32)>, Option<T::AccountId>), ListError> {
+		let iter: Box<dyn Iterator<Item = Node<T, I>>> = match start {
+			Some(start) => Box::new(Self::iter_from(start)?),
+			None => Box::new(Self::iter()),
+		};
+
+		let mut distribution: Vec<(T::Score, u32)> = Vec::new();
+		let mut counted = 0;
+		let mut last = None;
+		for node in iter.take(limit as usize) {
+			match distribution.last_mut() {
+				Some((upper, count)) if *upper == node.bag_upper => *count += 1,
+				_ => distribution.push((node.bag_upper, 1)),
+			}
+			counted += 1;
+			last = Some(node.id);
+		}
+
+		Ok((distribution, if counted == limit { last } else { None }))
+	}
+
 	/// Same as `iter`, but we start from a specific node.
 	///
 	/// All items after this node are returned, excluding `start` itself.
@@ -289,6 +416,37 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		Ok(start_bag.chain(leftover_bags))
 	}
 
+	/// The ids directly in front of (`heavier`) and behind (`lighter`) `id` in the global order
+	/// of the list, as returned by [`Self::iter`].
+	///
+	/// Either side is `None` if `id` is at that end of the list. Returns `None` if `id` is not
+	/// in the list.
+	pub(crate) fn neighbours(
+		id: &T::AccountId,
+	) -> Option<(Option<T::AccountId>, Option<T::AccountId>)> {
+		let node = Node::<T, I>::get(id)?;
+		let lighter = Self::iter_from(id).ok()?.next().map(|n| n.id);
+
+		let heavier = node.prev.clone().or_else(|| {
+			// the node is the head of its bag: look for the tail of the closest heavier bag.
+			let thresholds = T::BagThresholds::get();
+			let idx = thresholds.partition_point(|&threshold| node.bag_upper >= threshold);
+			let heavier_bags = thresholds.iter().skip(idx).copied();
+			let heavier_bags: Box<dyn Iterator<Item = T::Score>> = if thresholds.last() ==
+				Some(&T::Score::max_value()) ||
+				node.bag_upper == T::Score::max_value()
+			{
+				Box::new(heavier_bags)
+			} else {
+				Box::new(heavier_bags.chain(iter::once(T::Score::max_value())))
+			};
+
+			heavier_bags.filter_map(Bag::<T, I>::get).find_map(|bag| bag.tail)
+		});
+
+		Some((heavier, lighter))
+	}
+
 	/// Insert several ids into the appropriate bags in the list. Continues with insertions
 	/// if duplicates are detected.
 	///
@@ -312,6 +470,17 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 	///
 	/// Returns an error if the list already contains `id`.
 	pub(crate) fn insert(id: T::AccountId, score: T::Score) -> Result<(), ListError> {
+		Self::insert_with_metadata(id, score, Default::default())
+	}
+
+	/// Insert a new id along with its `metadata` into the appropriate bag in the list.
+	///
+	/// Returns an error if the list already contains `id`.
+	pub(crate) fn insert_with_metadata(
+		id: T::AccountId,
+		score: T::Score,
+		metadata: T::NodeMetadata,
+	) -> Result<(), ListError> {
 		if Self::contains(&id) {
 			return Err(ListError::Duplicate)
 		}
@@ -319,7 +488,7 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		let bag_score = notional_bag_for::<T, I>(score);
 		let mut bag = Bag::<T, I>::get_or_make(bag_score);
 		// unchecked insertion is okay; we just got the correct `notional_bag_for`.
-		bag.insert_unchecked(id.clone(), score);
+		bag.insert_unchecked(id.clone(), score, metadata);
 
 		// new inserts are always the tail, so we must write the bag.
 		bag.put();
@@ -336,6 +505,17 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		Ok(())
 	}
 
+	/// Update the metadata of `id`, returning an error if `id` does not exists.
+	pub(crate) fn update_metadata(
+		id: &T::AccountId,
+		metadata: T::NodeMetadata,
+	) -> Result<(), ListError> {
+		let mut node = Node::<T, I>::get(id).ok_or(ListError::NodeNotFound)?;
+		node.metadata = metadata;
+		node.put();
+		Ok(())
+	}
+
 	/// Remove an id from the list, returning an error if `id` does not exists.
 	pub(crate) fn remove(id: &T::AccountId) -> Result<(), ListError> {
 		if !Self::contains(id) {
@@ -510,6 +690,7 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 	/// This should be called from the call-site, whenever one of the mutating apis (e.g. `insert`)
 	/// is being used, after all other staking data (such as counter) has been updated. It checks:
 	///
+	/// * every node decodes,
 	/// * there are no duplicate ids,
 	/// * length of this list is in sync with `ListNodes::count()`,
 	/// * and sanity-checks all bags and nodes. This will cascade down all the checks and makes sure
@@ -521,6 +702,12 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 			"duplicate identified",
 		);
 
+		ensure!(
+			crate::ListNodes::<T, I>::iter_keys()
+				.all(|id| crate::ListNodes::<T, I>::try_get(&id).is_ok()),
+			"a node does not decode, was `NodeMetadata` changed without a migration?",
+		);
+
 		let iter_count = Self::iter().count() as u32;
 		let stored_count = crate::ListNodes::<T, I>::count();
 		let nodes_count = crate::ListNodes::<T, I>::iter().count() as u32;
@@ -618,6 +805,8 @@ impl<T: Config<I>, I: 'static> Bag<T, I> {
 
 	/// Get a bag by its upper score.
 	pub(crate) fn get(bag_upper: T::Score) -> Option<Bag<T, I>> {
+		#[cfg(test)]
+		reads::bag();
 		crate::ListBags::<T, I>::try_get(bag_upper).ok().map(|mut bag| {
 			bag.bag_upper = bag_upper;
 			bag
@@ -666,7 +855,7 @@ impl<T: Config<I>, I: 'static> Bag<T, I> {
 	///
 	/// Storage note: this modifies storage, but only for the nodes. You still need to call
 	/// `self.put()` after use.
-	fn insert_unchecked(&mut self, id: T::AccountId, score: T::Score) {
+	fn insert_unchecked(&mut self, id: T::AccountId, score: T::Score, metadata: T::NodeMetadata) {
 		// insert_node will overwrite `prev`, `next` and `bag_upper` to the proper values. As long
 		// as this bag is the correct one, we're good. All calls to this must come after getting the
 		// correct [`notional_bag_for`].
@@ -676,6 +865,7 @@ impl<T: Config<I>, I: 'static> Bag<T, I> {
 			next: None,
 			bag_upper: Zero::zero(),
 			score,
+			metadata,
 			_phantom: PhantomData,
 		});
 	}
@@ -806,6 +996,7 @@ pub struct Node<T: Config<I>, I: 'static = ()> {
 	pub(crate) next: Option<T::AccountId>,
 	pub(crate) bag_upper: T::Score,
 	pub(crate) score: T::Score,
+	pub(crate) metadata: T::NodeMetadata,
 	#[codec(skip)]
 	pub(crate) _phantom: PhantomData<I>,
 }
@@ -813,6 +1004,8 @@ pub struct Node<T: Config<I>, I: 'static = ()> {
 impl<T: Config<I>, I: 'static> Node<T, I> {
 	/// Get a node by id.
 	pub fn get(id: &T::AccountId) -> Option<Node<T, I>> {
+		#[cfg(test)]
+		reads::node();
 		crate::ListNodes::<T, I>::try_get(id).ok()
 	}
 
@@ -875,6 +1068,11 @@ impl<T: Config<I>, I: 'static> Node<T, I> {
 		self.score
 	}
 
+	/// Get the auxiliary metadata of the node.
+	pub(crate) fn metadata(&self) -> &T::NodeMetadata {
+		&self.metadata
+	}
+
 	/// Get the underlying voter (public fo tests).
 	#[cfg(feature = "std")]
 	#[allow(dead_code)]
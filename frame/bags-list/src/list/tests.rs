@@ -29,7 +29,15 @@ fn node(
 	next: Option<AccountId>,
 	bag_upper: VoteWeight,
 ) -> Node<Runtime> {
-	Node::<Runtime> { id, prev, next, bag_upper, score: bag_upper, _phantom: PhantomData }
+	Node::<Runtime> {
+		id,
+		prev,
+		next,
+		bag_upper,
+		score: bag_upper,
+		metadata: 0,
+		_phantom: PhantomData,
+	}
 }
 
 #[test]
@@ -91,6 +99,25 @@ fn notional_bag_for_works() {
 	assert_eq!(notional_bag_for::<Runtime, _>(max_explicit_threshold + 1), VoteWeight::MAX);
 }
 
+#[test]
+fn notional_bag_lower_bound_works() {
+	// the first bag starts at zero.
+	assert_eq!(notional_bag_lower_bound::<Runtime, _>(10), Some(0));
+
+	// other bags start right above the previous threshold.
+	assert_eq!(notional_bag_lower_bound::<Runtime, _>(20), Some(11));
+	assert_eq!(notional_bag_lower_bound::<Runtime, _>(1_000), Some(61));
+	assert_eq!(notional_bag_lower_bound::<Runtime, _>(VoteWeight::MAX), Some(10_001));
+
+	// and the lower bound falls into the given bag.
+	assert_eq!(notional_bag_for::<Runtime, _>(61), 1_000);
+
+	// anything that is not a threshold is not a bag.
+	assert_eq!(notional_bag_lower_bound::<Runtime, _>(0), None);
+	assert_eq!(notional_bag_lower_bound::<Runtime, _>(999), None);
+	assert_eq!(notional_bag_lower_bound::<Runtime, _>(10_001), None);
+}
+
 #[test]
 fn remove_last_node_in_bags_cleans_bag() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -357,7 +384,7 @@ mod list {
 
 		// make sure there are no duplicates.
 		ExtBuilder::default().build_and_execute_no_post_check(|| {
-			Bag::<Runtime>::get(10).unwrap().insert_unchecked(2, 10);
+			Bag::<Runtime>::get(10).unwrap().insert_unchecked(2, 10, 0);
 			assert_eq!(List::<Runtime>::try_state(), Err("duplicate identified"));
 		});
 
@@ -396,6 +423,7 @@ mod list {
 				next: None,
 				bag_upper: 15,
 				score: 15,
+				metadata: 0,
 				_phantom: PhantomData,
 			};
 			let node_11_no_bag = Node::<Runtime> {
@@ -404,6 +432,7 @@ mod list {
 				next: None,
 				bag_upper: 15,
 				score: 15,
+				metadata: 0,
 				_phantom: PhantomData,
 			};
 
@@ -436,6 +465,7 @@ mod list {
 				next: Some(2),
 				bag_upper: 1_000,
 				score: 1_000,
+				metadata: 0,
 				_phantom: PhantomData,
 			};
 			assert!(!crate::ListNodes::<Runtime>::contains_key(42));
@@ -466,6 +496,7 @@ mod list {
 				next: None,
 				bag_upper: 1_000,
 				score: 1_000,
+				metadata: 0,
 				_phantom: PhantomData,
 			};
 			assert!(!crate::ListNodes::<Runtime>::contains_key(42));
@@ -496,6 +527,7 @@ mod list {
 				next: Some(2),
 				bag_upper: 1_000,
 				score: 1_000,
+				metadata: 0,
 				_phantom: PhantomData,
 			};
 			assert!(!crate::ListNodes::<Runtime>::contains_key(42));
@@ -526,6 +558,7 @@ mod list {
 				next: Some(42),
 				bag_upper: 1_000,
 				score: 1_000,
+				metadata: 0,
 				_phantom: PhantomData,
 			};
 			assert!(!crate::ListNodes::<Runtime>::contains_key(42));
@@ -591,6 +624,7 @@ mod bags {
 				next: None,
 				bag_upper,
 				score: bag_upper,
+				metadata: 0,
 				_phantom: PhantomData,
 			};
 
@@ -607,6 +641,7 @@ mod bags {
 					prev: Some(1),
 					next: None,
 					id: 42,
+					metadata: 0,
 					_phantom: PhantomData
 				}
 			);
@@ -622,6 +657,7 @@ mod bags {
 				next: None,
 				bag_upper,
 				score: bag_upper,
+				metadata: 0,
 				_phantom: PhantomData,
 			};
 
@@ -650,6 +686,7 @@ mod bags {
 				next: Some(101),
 				bag_upper: 20,
 				score: 20,
+				metadata: 0,
 				_phantom: PhantomData,
 			};
 			bag_20.insert_node_unchecked(node_61);
@@ -664,6 +701,7 @@ mod bags {
 					next: None,
 					bag_upper: 20,
 					score: 20,
+					metadata: 0,
 					_phantom: PhantomData,
 				}
 			);
@@ -698,6 +736,7 @@ mod bags {
 					next: None,
 					bag_upper: bag_1000.bag_upper,
 					score: 500,
+					metadata: 0,
 					_phantom: PhantomData
 				}
 			);
@@ -741,6 +780,7 @@ mod bags {
 					next: None,
 					bag_upper: bag_1000.bag_upper,
 					score: 0,
+					metadata: 0,
 					_phantom: PhantomData
 				},
 			);
@@ -892,6 +932,7 @@ mod bags {
 				next: Some(3),
 				bag_upper: 10, // should be 1_000
 				score: 10,
+				metadata: 0,
 				_phantom: PhantomData,
 			};
 			let mut bag_1000 = Bag::<Runtime>::get(1_000).unwrap();
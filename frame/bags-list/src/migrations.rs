@@ -20,7 +20,7 @@
 use codec::{Decode, Encode};
 use core::marker::PhantomData;
 use frame_election_provider_support::ScoreProvider;
-use frame_support::traits::OnRuntimeUpgrade;
+use frame_support::traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
 
 #[cfg(feature = "try-runtime")]
 use frame_support::ensure;
@@ -82,6 +82,17 @@ mod old {
 	#[frame_support::storage_alias]
 	pub type CounterForListNodes<T: crate::Config<I>, I: 'static> =
 		StorageValue<crate::Pallet<T, I>, u32, ValueQuery>;
+
+	#[derive(Encode, Decode)]
+	pub struct PreMetadataNode<T: crate::Config<I>, I: 'static = ()> {
+		pub id: T::AccountId,
+		pub prev: Option<T::AccountId>,
+		pub next: Option<T::AccountId>,
+		pub bag_upper: T::Score,
+		pub score: T::Score,
+		#[codec(skip)]
+		pub _phantom: PhantomData<I>,
+	}
 }
 
 /// A struct that migrates all bags lists to contain a score value.
@@ -109,6 +120,7 @@ impl<T: crate::Config<I>, I: 'static> OnRuntimeUpgrade for AddScore<T, I> {
 				next: node.next,
 				bag_upper: node.bag_upper,
 				score,
+				metadata: Default::default(),
 				_phantom: node._phantom,
 			};
 
@@ -136,3 +148,56 @@ impl<T: crate::Config<I>, I: 'static> OnRuntimeUpgrade for AddScore<T, I> {
 		Ok(())
 	}
 }
+
+/// A struct that migrates all nodes to store the default [`crate::Config::NodeMetadata`]
+/// alongside their score.
+pub struct AddMetadata<T: crate::Config<I>, I: 'static = ()>(sp_std::marker::PhantomData<(T, I)>);
+impl<T: crate::Config<I>, I: 'static> OnRuntimeUpgrade for AddMetadata<T, I> {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let onchain = crate::Pallet::<T, I>::on_chain_storage_version();
+		ensure!(onchain < 1, "this migration can be deleted");
+		Ok(crate::ListNodes::<T, I>::count().encode())
+	}
+
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		let onchain = crate::Pallet::<T, I>::on_chain_storage_version();
+		if onchain > 0 {
+			crate::log!(info, "AddMetadata should be removed");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated = 0u64;
+		crate::ListNodes::<T, I>::translate::<old::PreMetadataNode<T, I>, _>(|_key, node| {
+			translated += 1;
+			Some(crate::Node {
+				id: node.id,
+				prev: node.prev,
+				next: node.next,
+				bag_upper: node.bag_upper,
+				score: node.score,
+				metadata: Default::default(),
+				_phantom: node._phantom,
+			})
+		});
+		StorageVersion::new(1).put::<crate::Pallet<T, I>>();
+
+		crate::log!(info, "added the default metadata to {} nodes", translated);
+
+		T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(node_count_before: Vec<u8>) -> Result<(), &'static str> {
+		let node_count_before: u32 = Decode::decode(&mut node_count_before.as_slice())
+			.expect("the state parameter should be something that was generated by pre_upgrade");
+		ensure!(
+			crate::Pallet::<T, I>::on_chain_storage_version() == 1,
+			"this migration needs to be run"
+		);
+		let iter_node_count_after: u32 = crate::ListNodes::<T, I>::iter().count() as u32;
+		ensure!(iter_node_count_after == node_count_before, "Not all nodes were migrated.");
+		ensure!(crate::ListNodes::<T, I>::count() == iter_node_count_after, "Node count is wrong.");
+		Ok(())
+	}
+}
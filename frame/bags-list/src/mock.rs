@@ -75,12 +75,25 @@ impl frame_system::Config for Runtime {
 
 parameter_types! {
 	pub static BagThresholds: &'static [VoteWeight] = &[10, 20, 30, 40, 50, 60, 1_000, 2_000, 10_000];
+	pub static MaxReasonableWeight: Option<VoteWeight> = None;
+	pub static VetoedRemovals: Vec<AccountId> = vec![];
+}
+
+pub struct RemoveGuardMock;
+impl bags_list::OnRemoveGuard<AccountId> for RemoveGuardMock {
+	fn can_remove(id: &AccountId) -> bool {
+		!VetoedRemovals::get().contains(id)
+	}
 }
 
 impl bags_list::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type BagThresholds = BagThresholds;
+	type MaxReasonableWeight = MaxReasonableWeight;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type OnRemoveGuard = RemoveGuardMock;
+	type NodeMetadata = u32;
 	type ScoreProvider = StakingMock;
 	type Score = VoteWeight;
 }
@@ -82,6 +82,118 @@ mod pallet {
 		});
 	}
 
+	#[test]
+	fn rebag_only_reports_a_move() {
+		ExtBuilder::default().add_ids(vec![(42, 20)]).build_and_execute(|| {
+			System::set_block_number(1);
+			let rebagged = || {
+				System::events().iter().any(|r| {
+					matches!(r.event, RuntimeEvent::BagsList(Event::Rebagged { who: 42, .. }))
+				})
+			};
+
+			// when the score changes within the range of the current bag
+			StakingMock::set_score_of(&42, 19);
+			assert_ok!(BagsList::rebag(RuntimeOrigin::signed(0), 42));
+
+			// then nothing moves and nothing is reported
+			assert!(!rebagged());
+			assert_eq!(List::<Runtime>::get_score(&42).unwrap(), 19);
+
+			// when the score changes enough to move bags
+			StakingMock::set_score_of(&42, 2_000);
+			assert_ok!(BagsList::rebag(RuntimeOrigin::signed(0), 42));
+
+			// then the move is reported
+			assert!(rebagged());
+		});
+	}
+
+	#[test]
+	fn rebag_and_report_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			System::set_block_number(1);
+			let reported = || {
+				System::events()
+					.into_iter()
+					.filter_map(|r| match r.event {
+						RuntimeEvent::BagsList(Event::RebaggedWithNeighbors {
+							who,
+							heavier,
+							lighter,
+						}) => Some((who, heavier, lighter)),
+						_ => None,
+					})
+					.collect::<Vec<_>>()
+			};
+			let neighbours_in_order = |who: AccountId| {
+				let order = List::<Runtime>::iter().map(|n| *n.id()).collect::<Vec<_>>();
+				let pos = order.iter().position(|id| *id == who).unwrap();
+				(pos.checked_sub(1).map(|p| order[p]), order.get(pos + 1).copied())
+			};
+
+			// given
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![2, 3, 4])]);
+
+			// when 3 moves to a new, heaviest bag
+			StakingMock::set_score_of(&3, 2_000);
+			assert_ok!(BagsList::rebag_and_report(RuntimeOrigin::signed(0), 3));
+
+			// then it has no heavier neighbour, and the head of its old bag behind it
+			assert_eq!(
+				List::<Runtime>::get_bags(),
+				vec![(10, vec![1]), (1_000, vec![2, 4]), (2_000, vec![3])]
+			);
+			assert_eq!(neighbours_in_order(3), (None, Some(2)));
+			assert_eq!(reported(), vec![(3, None, Some(2))]);
+
+			// when 4 moves to a new bag between two non-empty bags
+			StakingMock::set_score_of(&4, 30);
+			assert_ok!(BagsList::rebag_and_report(RuntimeOrigin::signed(0), 4));
+
+			// then its neighbours are the tail of the bag above and the head of the bag below
+			assert_eq!(
+				List::<Runtime>::get_bags(),
+				vec![(10, vec![1]), (30, vec![4]), (1_000, vec![2]), (2_000, vec![3])]
+			);
+			assert_eq!(neighbours_in_order(4), (Some(2), Some(1)));
+			assert_eq!(reported().last(), Some(&(4, Some(2), Some(1))));
+
+			// when 1 moves into the tail of a pre-existing bag
+			StakingMock::set_score_of(&1, 2_000);
+			assert_ok!(BagsList::rebag_and_report(RuntimeOrigin::signed(0), 1));
+
+			// then it sits behind the previous tail of that bag
+			assert_eq!(neighbours_in_order(1), (Some(3), Some(2)));
+			assert_eq!(reported().last(), Some(&(1, Some(3), Some(2))));
+			assert_eq!(reported().len(), 3);
+		});
+	}
+
+	#[test]
+	fn rebag_and_report_noop_emits_no_event() {
+		ExtBuilder::default().build_and_execute(|| {
+			System::set_block_number(1);
+
+			// when the score changes within the range of the current bag
+			StakingMock::set_score_of(&3, 999);
+			assert_ok!(BagsList::rebag_and_report(RuntimeOrigin::signed(0), 3));
+
+			// then nothing moves and nothing is reported
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![2, 3, 4])]);
+			assert!(!System::events().iter().any(|r| matches!(
+				r.event,
+				RuntimeEvent::BagsList(Event::RebaggedWithNeighbors { .. })
+			)));
+
+			// and an id that is not in the list is rejected
+			assert_noop!(
+				BagsList::rebag_and_report(RuntimeOrigin::signed(0), 42),
+				crate::pallet::Error::<Runtime>::List(ListError::NodeNotFound)
+			);
+		});
+	}
+
 	// Rebagging the tail of a bag results in the old bag having a new tail and an overall correct
 	// state.
 	#[test]
@@ -119,6 +231,31 @@ mod pallet {
 		});
 	}
 
+	// Rebagging performs exactly the bag and node reads assumed by its weight.
+	#[test]
+	fn rebag_reads_are_bounded() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![2, 3, 4])]);
+			list::reads::take();
+
+			// when rebagging a non-terminal node
+			StakingMock::set_score_of(&3, 10);
+			assert_ok!(BagsList::rebag(RuntimeOrigin::signed(0), 3));
+
+			// then the node, its prev and next, the new bag and its tail are read.
+			assert_eq!(list::reads::take(), (1, 4));
+
+			// when rebagging a terminal node
+			StakingMock::set_score_of(&4, 10);
+			assert_ok!(BagsList::rebag(RuntimeOrigin::signed(0), 4));
+
+			// then the node, its prev, the old and new bags and the new bag tail are read.
+			assert_eq!(list::reads::take(), (2, 3));
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1, 3, 4]), (1_000, vec![2])]);
+		});
+	}
+
 	// Rebagging the head of a bag results in the old bag having a new head and an overall correct
 	// state.
 	#[test]
@@ -171,6 +308,116 @@ mod pallet {
 		});
 	}
 
+	#[test]
+	fn force_rebag_into_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![2, 3, 4])]);
+
+			// only the force origin can force a rebag
+			assert_noop!(
+				BagsList::force_rebag_into(RuntimeOrigin::signed(1), 1, 1_000),
+				sp_runtime::DispatchError::BadOrigin
+			);
+
+			// when
+			assert_ok!(BagsList::force_rebag_into(RuntimeOrigin::root(), 1, 1_000));
+
+			// then the id is at the tail of the bag, with the lowest score of that bag
+			assert_eq!(List::<Runtime>::get_bags(), vec![(1_000, vec![2, 3, 4, 1])]);
+			assert_eq!(List::<Runtime>::get_score(&1).unwrap(), 61);
+			assert_ok!(List::<Runtime>::try_state());
+
+			// when the bag is not a threshold
+			assert_noop!(
+				BagsList::force_rebag_into(RuntimeOrigin::root(), 1, 999),
+				Error::<Runtime>::List(ListError::InvalidBag)
+			);
+
+			// when the id is not in the list
+			assert_noop!(
+				BagsList::force_rebag_into(RuntimeOrigin::root(), 42, 10),
+				Error::<Runtime>::List(ListError::NodeNotFound)
+			);
+
+			// the implied last bag can be used as well
+			assert_ok!(BagsList::force_rebag_into(RuntimeOrigin::root(), 2, VoteWeight::MAX));
+			assert_eq!(
+				List::<Runtime>::get_bags(),
+				vec![(1_000, vec![3, 4, 1]), (VoteWeight::MAX, vec![2])]
+			);
+			assert_eq!(List::<Runtime>::get_score(&2).unwrap(), 10_001);
+		});
+	}
+
+	#[test]
+	fn node_metadata_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			let list_with_metadata = || BagsList::iter_with_metadata().collect::<Vec<_>>();
+
+			// given ids inserted with the default metadata
+			assert_eq!(list_with_metadata(), vec![(2, 0), (3, 0), (4, 0), (1, 0)]);
+
+			// when inserting ids with metadata
+			assert_ok!(BagsList::insert_with_metadata(5, 2_000, 7));
+			assert_ok!(BagsList::insert_with_metadata(6, 15, 9));
+			assert_eq!(BagsList::insert_with_metadata(5, 10, 1), Err(ListError::Duplicate));
+
+			// then the metadata is stored alongside them
+			assert_eq!(
+				List::<Runtime>::get_bags(),
+				vec![(10, vec![1]), (20, vec![6]), (1_000, vec![2, 3, 4]), (2_000, vec![5])]
+			);
+			assert_eq!(list_with_metadata(), vec![(5, 7), (2, 0), (3, 0), (4, 0), (6, 9), (1, 0)]);
+
+			// when updating the metadata, the id does not move
+			assert_ok!(BagsList::update_metadata(&2, 42));
+			assert_eq!(BagsList::update_metadata(&42, 1), Err(ListError::NodeNotFound));
+
+			// and rebagging keeps the metadata
+			StakingMock::set_score_of(&6, 2_000);
+			assert_ok!(BagsList::rebag(RuntimeOrigin::signed(0), 6));
+
+			assert_eq!(list_with_metadata(), vec![(5, 7), (6, 9), (2, 42), (3, 0), (4, 0), (1, 0)]);
+			assert_ok!(List::<Runtime>::try_state());
+		});
+	}
+
+	#[test]
+	fn bag_distribution_works() {
+		ExtBuilder::default()
+			.add_ids(vec![(5, 2_000), (6, 2_000), (7, u64::MAX)])
+			.build_and_execute(|| {
+				let expected = List::<Runtime>::get_bags()
+					.into_iter()
+					.rev()
+					.map(|(upper, ids)| (upper, ids.len() as u32))
+					.collect::<Vec<_>>();
+				assert_eq!(expected, vec![(u64::MAX, 1), (2_000, 2), (1_000, 3), (10, 1)]);
+
+				// a page large enough for the whole list.
+				assert_eq!(BagsList::bag_distribution(None, 10), Ok((expected, None)));
+
+				// pages split bags, and continue from the last node counted.
+				assert_eq!(
+					BagsList::bag_distribution(None, 4),
+					Ok((vec![(u64::MAX, 1), (2_000, 2), (1_000, 1)], Some(2)))
+				);
+				assert_eq!(
+					BagsList::bag_distribution(Some(&2), 4),
+					Ok((vec![(1_000, 2), (10, 1)], None))
+				);
+				assert_eq!(BagsList::bag_distribution(Some(&42), 4), Err(ListError::NodeNotFound));
+
+				// empty bags are skipped once their last node leaves.
+				assert_ok!(BagsList::on_remove(&1));
+				assert_eq!(
+					BagsList::bag_distribution(None, 10),
+					Ok((vec![(u64::MAX, 1), (2_000, 2), (1_000, 3)], None))
+				);
+			});
+	}
+
 	#[test]
 	#[should_panic = "thresholds must strictly increase, and have no duplicates"]
 	fn duplicate_in_bags_threshold_panics() {
@@ -508,6 +755,61 @@ mod sorted_list_provider {
 		});
 	}
 
+	#[test]
+	fn total_score_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given
+			assert_eq!(BagsList::total_score(), 10 + 3 * 1_000);
+
+			// when inserting
+			assert_ok!(BagsList::on_insert(201, 50));
+			assert_ok!(BagsList::on_insert(202, 2_000));
+			// then
+			assert_eq!(BagsList::total_score(), 10 + 3 * 1_000 + 50 + 2_000);
+
+			// when updating
+			assert_ok!(BagsList::on_update(&201, 60));
+			// then
+			assert_eq!(BagsList::total_score(), 10 + 3 * 1_000 + 60 + 2_000);
+
+			// when removing
+			BagsList::on_remove(&2).unwrap();
+			BagsList::on_remove(&202).unwrap();
+			// then
+			assert_eq!(BagsList::total_score(), 10 + 2 * 1_000 + 60);
+		});
+
+		// saturates at the maximum score.
+		ExtBuilder::default()
+			.add_ids(vec![(42, VoteWeight::MAX)])
+			.build_and_execute(|| {
+				assert_eq!(BagsList::total_score(), VoteWeight::MAX);
+			});
+	}
+
+	#[test]
+	fn rank_of_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given
+			assert_eq!(get_list_as_ids(), vec![2, 3, 4, 1]);
+
+			// head, middle and tail of the heaviest bag.
+			assert_eq!(BagsList::rank_of(&2), Some((0, 4)));
+			assert_eq!(BagsList::rank_of(&3), Some((1, 4)));
+			assert_eq!(BagsList::rank_of(&4), Some((2, 4)));
+			// tail of the list.
+			assert_eq!(BagsList::rank_of(&1), Some((3, 4)));
+			// absent.
+			assert_eq!(BagsList::rank_of(&42), None);
+
+			// when a heavier node is inserted
+			assert_ok!(BagsList::on_insert(5, 2_000));
+			// then everyone else moves one place down.
+			assert_eq!(BagsList::rank_of(&5), Some((0, 5)));
+			assert_eq!(BagsList::rank_of(&4), Some((3, 5)));
+		});
+	}
+
 	#[test]
 	fn on_insert_works() {
 		ExtBuilder::default().build_and_execute(|| {
@@ -609,6 +911,59 @@ mod sorted_list_provider {
 		});
 	}
 
+	#[test]
+	fn unreasonable_scores_are_clamped() {
+		ExtBuilder::default().add_ids(vec![(42, 20)]).build_and_execute(|| {
+			MaxReasonableWeight::set(Some(10_000));
+
+			// when updating to a score above the cap
+			assert_ok!(BagsList::on_update(&42, 10_001));
+
+			// then the score is clamped to the cap,
+			assert_eq!(BagsList::get_score(&42), Ok(10_000));
+			assert_eq!(
+				List::<Runtime>::get_bags(),
+				vec![(10, vec![1]), (1_000, vec![2, 3, 4]), (10_000, vec![42])]
+			);
+
+			// and the same holds when inserting.
+			assert_ok!(BagsList::on_insert(43, u64::MAX));
+			assert_eq!(BagsList::get_score(&43), Ok(10_000));
+			assert_eq!(
+				List::<Runtime>::get_bags(),
+				vec![(10, vec![1]), (1_000, vec![2, 3, 4]), (10_000, vec![42, 43])]
+			);
+		});
+	}
+
+	#[test]
+	fn on_remove_consults_guard() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given a guard objecting to the removal of 1.
+			VetoedRemovals::set(vec![1]);
+
+			// an absent id is still reported as such.
+			assert_noop!(BagsList::on_remove(&42), ListError::NodeNotFound);
+
+			// while others are removed as usual.
+			assert_ok!(BagsList::on_remove(&2));
+			assert_eq!(get_list_as_ids(), vec![3, 4, 1]);
+		});
+	}
+
+	#[test]
+	#[cfg_attr(debug_assertions, should_panic(expected = "Defensive failure has been triggered!"))]
+	fn on_remove_reports_but_does_not_veto_guarded_removal() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given a guard objecting to the removal of 1.
+			VetoedRemovals::set(vec![1]);
+
+			// then 1 is removed regardless, so the list does not drift from its source of truth.
+			assert_ok!(BagsList::on_remove(&1));
+			assert_eq!(get_list_as_ids(), vec![2, 3, 4]);
+		});
+	}
+
 	#[test]
 	fn on_remove_works() {
 		let ensure_left = |id, counter| {
@@ -660,4 +1015,88 @@ mod sorted_list_provider {
 			assert!(non_existent_ids.iter().all(|id| !BagsList::contains(id)));
 		})
 	}
+
+	#[test]
+	fn unsafe_clear_works() {
+		ExtBuilder::default()
+			.add_ids(vec![(5, 2_000), (6, 3_000)])
+			.build_and_execute(|| {
+				// when
+				BagsList::unsafe_clear();
+
+				// then
+				assert_eq!(BagsList::count(), 0);
+				assert_eq!(BagsList::iter().count(), 0);
+				assert_eq!(ListNodes::<Runtime>::iter().count(), 0);
+				assert_eq!(ListBags::<Runtime>::iter().count(), 0);
+				assert_ok!(BagsList::try_state());
+			})
+	}
+
+	#[test]
+	fn clear_resumes_from_cursor() {
+		let mut ext = ExtBuilder::default().add_ids(vec![(5, 2_000), (6, 3_000)]).build();
+		// the limit only applies to keys in the backend, so commit after each block.
+		let mut clear_in_block = |cursor| {
+			ext.commit_all().unwrap();
+			ext.execute_with(|| {
+				let cursor = BagsList::clear(3, cursor);
+				(cursor, ListBags::<Runtime>::iter().count(), BagsList::count())
+			})
+		};
+
+		// given 4 bags and 6 nodes, when removing 3 keys per block.
+		let (cursor, bags, nodes) = clear_in_block(None);
+		assert!(matches!(cursor, Some(ClearCursor::Bags(_))));
+		assert_eq!((bags, nodes), (1, 6));
+		let (cursor, bags, nodes) = clear_in_block(cursor);
+		assert!(matches!(cursor, Some(ClearCursor::Nodes(Some(_)))));
+		assert_eq!((bags, nodes), (0, 4));
+		let (cursor, bags, nodes) = clear_in_block(cursor);
+		assert!(matches!(cursor, Some(ClearCursor::Nodes(Some(_)))));
+		assert_eq!((bags, nodes), (0, 1));
+		let (cursor, bags, nodes) = clear_in_block(cursor);
+
+		// then
+		assert_eq!(cursor, None);
+		assert_eq!((bags, nodes), (0, 0));
+		ext.execute_with(|| {
+			assert_eq!(ListNodes::<Runtime>::iter().count(), 0);
+			assert_ok!(BagsList::try_state());
+		});
+	}
+}
+
+mod migrations {
+	use super::*;
+	use codec::Encode;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	#[test]
+	fn add_metadata_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given nodes stored without metadata, as before it was introduced.
+			for (id, _) in GENESIS_IDS {
+				let mut encoded = ListNodes::<Runtime>::get(id).unwrap().encode();
+				encoded.truncate(encoded.len() - 0u32.encoded_size());
+				frame_support::storage::unhashed::put_raw(
+					&ListNodes::<Runtime>::hashed_key_for(id),
+					&encoded,
+				);
+			}
+			StorageVersion::new(0).put::<BagsList>();
+			assert!(List::<Runtime>::try_state().is_err());
+
+			// when
+			crate::migrations::AddMetadata::<Runtime>::on_runtime_upgrade();
+
+			// then
+			assert_eq!(BagsList::on_chain_storage_version(), 1);
+			assert_ok!(List::<Runtime>::try_state());
+			assert_eq!(
+				BagsList::iter_with_metadata().collect::<Vec<_>>(),
+				vec![(2, 0), (3, 0), (4, 0), (1, 0)]
+			);
+		})
+	}
 }
@@ -47,6 +47,7 @@ pub trait WeightInfo {
 	fn rebag_non_terminal() -> Weight;
 	fn rebag_terminal() -> Weight;
 	fn put_in_front_of() -> Weight;
+	fn rebag_and_report() -> Weight;
 }
 
 /// Weights for pallet_bags_list using the Substrate node and recommended hardware.
@@ -80,6 +81,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(10 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `rebag_and_report` benchmark, see capsule-corp-ternoa/substrate#synth-1228.
+	// Storage: Staking Bonded (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: BagsList ListNodes (r:4 w:3)
+	// Storage: BagsList ListBags (r:201 w:2)
+	fn rebag_and_report() -> Weight {
+		Weight::from_ref_time(74_863_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(207 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -112,4 +124,15 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(10 as u64))
 			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `rebag_and_report` benchmark, see capsule-corp-ternoa/substrate#synth-1228.
+	// Storage: Staking Bonded (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: BagsList ListNodes (r:4 w:3)
+	// Storage: BagsList ListBags (r:201 w:2)
+	fn rebag_and_report() -> Weight {
+		Weight::from_ref_time(74_863_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(207 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
 }
@@ -39,8 +39,9 @@
 //! sub-bounties, as children of anh established bounty (called the parent in the context of it's
 //! children).
 //!
-//! > NOTE: The parent bounty cannot be closed if it has a non-zero number of it has active child
-//! > bounties associated with it.
+//! > NOTE: The parent bounty cannot be awarded if it has a non-zero number of active child
+//! > bounties associated with it. Closing it cancels its child bounties, and fails if any of them
+//! > cannot be canceled.
 //!
 //! ### Terminology
 //!
@@ -140,6 +141,14 @@ impl<AccountId: PartialEq + Clone + Ord, Balance, BlockNumber: Clone>
 	pub fn get_status(&self) -> BountyStatus<AccountId, BlockNumber> {
 		self.status.clone()
 	}
+
+	/// Getter for bounty value, to be used for child bounties.
+	pub fn get_value(&self) -> Balance
+	where
+		Balance: Clone,
+	{
+		self.value.clone()
+	}
 }
 
 /// The status of a bounty proposal.
@@ -182,6 +191,16 @@ pub trait ChildBountyManager<Balance> {
 
 	/// Get total curator fees of children-bounty curators.
 	fn children_curator_fees(bounty_id: BountyIndex) -> Balance;
+
+	/// Cancel the child bounties of a parent bounty that is being closed, returning the weight
+	/// consumed.
+	///
+	/// Child bounties that cannot be canceled are left in place, and keep the parent bounty from
+	/// being closed.
+	fn on_parent_closed(bounty_id: BountyIndex) -> Weight;
+
+	/// The maximum weight of [`Self::on_parent_closed`].
+	fn on_parent_closed_max_weight() -> Weight;
 }
 
 #[frame_support::pallet]
@@ -655,15 +674,19 @@ pub mod pallet {
 		/// Cancel a proposed or active bounty. All the funds will be sent to treasury and
 		/// the curator deposit will be unreserved if possible.
 		///
+		/// The child bounties of the bounty are canceled first, see
+		/// [`ChildBountyManager::on_parent_closed`].
+		///
 		/// Only `T::RejectOrigin` is able to cancel a bounty.
 		///
 		/// - `bounty_id`: Bounty ID to cancel.
 		///
 		/// # <weight>
-		/// - O(1).
+		/// - O(C) where C is the number of child bounties.
 		/// # </weight>
 		#[pallet::weight(<T as Config<I>>::WeightInfo::close_bounty_proposed()
-			.max(<T as Config<I>>::WeightInfo::close_bounty_active()))]
+			.max(<T as Config<I>>::WeightInfo::close_bounty_active())
+			.saturating_add(T::ChildBountyManager::on_parent_closed_max_weight()))]
 		pub fn close_bounty(
 			origin: OriginFor<T>,
 			#[pallet::compact] bounty_id: BountyIndex,
@@ -675,7 +698,8 @@ pub mod pallet {
 				|maybe_bounty| -> DispatchResultWithPostInfo {
 					let bounty = maybe_bounty.as_ref().ok_or(Error::<T, I>::InvalidIndex)?;
 
-					// Ensure no active child bounties before processing the call.
+					// Cancel the child bounties, ensuring none is left before processing the call.
+					let children_weight = T::ChildBountyManager::on_parent_closed(bounty_id);
 					ensure!(
 						T::ChildBountyManager::child_bounties_count(bounty_id) == 0,
 						Error::<T, I>::HasActiveChildBounty
@@ -738,7 +762,11 @@ pub mod pallet {
 					*maybe_bounty = None;
 
 					Self::deposit_event(Event::<T, I>::BountyCanceled { index: bounty_id });
-					Ok(Some(<T as Config<I>>::WeightInfo::close_bounty_active()).into())
+					Ok(Some(
+						<T as Config<I>>::WeightInfo::close_bounty_active()
+							.saturating_add(children_weight),
+					)
+					.into())
 				},
 			)
 		}
@@ -905,4 +933,12 @@ impl<Balance: Zero> ChildBountyManager<Balance> for () {
 	fn children_curator_fees(_bounty_id: BountyIndex) -> Balance {
 		Zero::zero()
 	}
+
+	fn on_parent_closed(_bounty_id: BountyIndex) -> Weight {
+		Weight::zero()
+	}
+
+	fn on_parent_closed_max_weight() -> Weight {
+		Weight::zero()
+	}
 }
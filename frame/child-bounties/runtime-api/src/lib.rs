@@ -0,0 +1,72 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for child-bounties pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_child_bounties::ChildBountiesStats;
+
+/// Index of a bounty or child-bounty, as used by the bounties pallets.
+pub type BountyIndex = u32;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for accessing information about child bounties.
+	pub trait ChildBountiesApi<AccountId, BlockNumber, Balance>
+		where AccountId: Codec, BlockNumber: Codec, Balance: Codec
+	{
+		/// Returns the `(parent_bounty_id, child_bounty_id)` of each active child-bounty that
+		/// `curator` is the curator of.
+		fn active_child_bounties_of_curator(curator: AccountId) -> Vec<(BountyIndex, BountyIndex)>;
+
+		/// Returns the block from which the awarded child-bounty can be claimed by its
+		/// beneficiary, or `None` if it is not pending payout.
+		fn child_bounty_unlock_at(
+			parent_bounty_id: BountyIndex,
+			child_bounty_id: BountyIndex,
+		) -> Option<BlockNumber>;
+
+		/// Returns the `(parent_bounty_id, child_bounty_id, beneficiary)` of up to `limit` awarded
+		/// child bounties that can be claimed at block `now`, in index order.
+		fn claimable_child_bounties(
+			now: BlockNumber,
+			limit: u32,
+		) -> Vec<(BountyIndex, BountyIndex, AccountId)>;
+
+		/// Returns the free balance of the account of the child-bounty, or zero if there is no
+		/// such child-bounty.
+		fn child_bounty_account_balance(
+			parent_bounty_id: BountyIndex,
+			child_bounty_id: BountyIndex,
+		) -> Balance;
+
+		/// Returns the parent bounty of the child-bounty, or `None` if there is no such
+		/// child-bounty.
+		fn parent_of_child(child_bounty_id: BountyIndex) -> Option<BountyIndex>;
+
+		/// Returns the sum of the curator fees of all child bounties with an accepted curator,
+		/// that are yet to be paid out.
+		fn pending_child_curator_fees() -> Balance;
+
+		/// Returns the number of child bounties in each state, the total value of open child
+		/// bounties, their pending curator fees, and the total claimed to date.
+		fn child_bounties_stats() -> ChildBountiesStats<Balance>;
+	}
+}
@@ -30,6 +30,9 @@ use pallet_treasury::Pallet as Treasury;
 
 const SEED: u32 = 0;
 
+/// Number of contributions made to a child-bounty before it is claimed.
+const CONTRIBUTIONS: u32 = 10;
+
 #[derive(Clone)]
 struct BenchmarkChildBounty<T: Config> {
 	/// Bounty ID.
@@ -65,7 +68,8 @@ fn setup_bounty<T: Config>(
 		T::DataDepositPerByte::get() * T::MaximumReasonLength::get().into();
 	let _ = T::Currency::make_free_balance_be(&caller, deposit);
 	let curator = account("curator", user, SEED);
-	let _ = T::Currency::make_free_balance_be(&curator, fee / 2u32.into());
+	// The curator reserves the same deposit when adding a child-bounty.
+	let _ = T::Currency::make_free_balance_be(&curator, fee / 2u32.into() + deposit);
 	let reason = vec![0; description as usize];
 	(caller, curator, fee, value, reason)
 }
@@ -73,9 +77,15 @@ fn setup_bounty<T: Config>(
 fn setup_child_bounty<T: Config>(user: u32, description: u32) -> BenchmarkChildBounty<T> {
 	let (caller, curator, fee, value, reason) = setup_bounty::<T>(user, description);
 	let child_curator = account("child-curator", user, SEED);
-	let _ = T::Currency::make_free_balance_be(&child_curator, fee / 2u32.into());
+	// Enough for the curator deposit, however low the child-bounty fee.
+	let _ = T::Currency::make_free_balance_be(
+		&child_curator,
+		fee / 2u32.into() + T::ChildCuratorDepositMin::get(),
+	);
 	let child_bounty_value = (value - fee) / 4u32.into();
-	let child_bounty_fee = child_bounty_value / 2u32.into();
+	// Stay within the maximum curator fee ratio.
+	let child_bounty_fee =
+		(T::MaxCuratorFeeRatio::get() * child_bounty_value).min(child_bounty_value / 2u32.into());
 
 	BenchmarkChildBounty::<T> {
 		bounty_id: 0,
@@ -91,6 +101,20 @@ fn setup_child_bounty<T: Config>(user: u32, description: u32) -> BenchmarkChildB
 	}
 }
 
+/// `b` beneficiaries sharing an award equally, the first one taking the remainder.
+fn split_beneficiaries<T: Config>(b: u32) -> Vec<(AccountIdLookupOf<T>, Perbill)> {
+	let share = Perbill::from_rational(1, b);
+	let first_share =
+		Perbill::from_parts(Perbill::one().deconstruct() - share.deconstruct() * (b - 1));
+	(0..b)
+		.map(|i| {
+			let beneficiary: T::AccountId = account("beneficiary", i, SEED);
+			let share = if i == 0 { first_share } else { share };
+			(T::Lookup::unlookup(beneficiary), share)
+		})
+		.collect()
+}
+
 fn activate_bounty<T: Config>(
 	user: u32,
 	description: u32,
@@ -222,11 +246,49 @@ benchmarks! {
 		setup_pot_account::<T>();
 		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
 		Treasury::<T>::on_initialize(T::BlockNumber::zero());
+		ChildBounties::<T>::child_curator_heartbeat(
+			RawOrigin::Signed(bounty_setup.child_curator.clone()).into(),
+			bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id,
+		)?;
 		frame_system::Pallet::<T>::set_block_number(T::BountyUpdatePeriod::get() + 1u32.into());
 		let caller = whitelisted_caller();
 	}: _(RawOrigin::Signed(caller), bounty_setup.bounty_id,
 			bounty_setup.child_bounty_id)
 
+	contribute_to_child_bounty {
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = bounty_setup.child_bounty_value;
+		let _ = T::Currency::make_free_balance_be(
+			&caller,
+			amount.saturating_mul(2u32.into()) + T::Currency::minimum_balance(),
+		);
+	}: _(RawOrigin::Signed(caller.clone()), bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id, amount)
+	verify {
+		assert_last_event::<T>(Event::ChildBountyFunded {
+			index: bounty_setup.bounty_id,
+			child_index: bounty_setup.child_bounty_id,
+			who: caller,
+			amount,
+		}.into())
+	}
+
+	child_curator_heartbeat {
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
+	}: _(RawOrigin::Signed(bounty_setup.child_curator), bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id)
+	verify {
+		assert_last_event::<T>(Event::ChildCuratorActive {
+			index: bounty_setup.bounty_id,
+			child_index: bounty_setup.child_bounty_id,
+			update_due: frame_system::Pallet::<T>::block_number() + T::BountyUpdatePeriod::get(),
+		}.into())
+	}
+
 	award_child_bounty {
 		setup_pot_account::<T>();
 		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
@@ -238,10 +300,26 @@ benchmarks! {
 		assert_last_event::<T>(Event::Awarded {
 			index: bounty_setup.bounty_id,
 			child_index: bounty_setup.child_bounty_id,
-			beneficiary: beneficiary_account
+			beneficiary: beneficiary_account,
+			unlock_at: frame_system::Pallet::<T>::block_number() + T::BountyDepositPayoutDelay::get(),
 		}.into())
 	}
 
+	award_child_bounty_split {
+		let b in 1 .. T::MaxAwardBeneficiaries::get();
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
+		let beneficiaries = split_beneficiaries::<T>(b);
+	}: _(RawOrigin::Signed(bounty_setup.child_curator), bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id, beneficiaries)
+	verify {
+		let splits = ChildBountyAwardSplits::<T>::get(
+			bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id,
+		);
+		assert_eq!(splits.map(|s| s.len()), Some(b as usize));
+	}
+
 	claim_child_bounty {
 		setup_pot_account::<T>();
 		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
@@ -269,10 +347,71 @@ benchmarks! {
 			"Beneficiary didn't get paid.");
 	}
 
+	// Worst case of a single beneficiary, paying out contributions on top of the value.
+	claim_child_bounty_contributed {
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
+		let amount = bounty_setup.child_bounty_value;
+		for i in 0 .. CONTRIBUTIONS {
+			let contributor: T::AccountId = account("contributor", i, SEED);
+			let _ = T::Currency::make_free_balance_be(
+				&contributor,
+				amount + T::Currency::minimum_balance(),
+			);
+			ChildBounties::<T>::contribute_to_child_bounty(
+				RawOrigin::Signed(contributor).into(),
+				bounty_setup.bounty_id,
+				bounty_setup.child_bounty_id,
+				amount,
+			)?;
+		}
+
+		let beneficiary_account: T::AccountId = account("beneficiary", 0, SEED);
+		ChildBounties::<T>::award_child_bounty(
+			RawOrigin::Signed(bounty_setup.child_curator.clone()).into(),
+			bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id,
+			T::Lookup::unlookup(beneficiary_account.clone()),
+		)?;
+
+		frame_system::Pallet::<T>::set_block_number(T::BountyDepositPayoutDelay::get());
+	}: claim_child_bounty(RawOrigin::Signed(bounty_setup.curator), bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id)
+	verify {
+		let payout = amount.saturating_mul((CONTRIBUTIONS + 1).into()) -
+			bounty_setup.child_bounty_fee;
+		ensure!(T::Currency::free_balance(&beneficiary_account) == payout,
+			"Beneficiary didn't get the contributions.");
+	}
+
+	claim_child_bounty_split {
+		let b in 1 .. T::MaxAwardBeneficiaries::get();
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
+
+		ChildBounties::<T>::award_child_bounty_split(
+			RawOrigin::Signed(bounty_setup.child_curator.clone()).into(),
+			bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id,
+			split_beneficiaries::<T>(b),
+		)?;
+
+		frame_system::Pallet::<T>::set_block_number(T::BountyDepositPayoutDelay::get());
+	}: claim_child_bounty(RawOrigin::Signed(bounty_setup.curator), bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id)
+	verify {
+		for i in 0 .. b {
+			let beneficiary_account: T::AccountId = account("beneficiary", i, SEED);
+			ensure!(!T::Currency::free_balance(&beneficiary_account).is_zero(),
+				"Beneficiary didn't get paid.");
+		}
+	}
+
 	// Best case scenario.
 	close_child_bounty_added {
 		setup_pot_account::<T>();
 		let mut bounty_setup = activate_bounty::<T>(0, T::MaximumReasonLength::get())?;
+		let reserved_before = T::Currency::reserved_balance(&bounty_setup.curator);
 
 		ChildBounties::<T>::add_child_bounty(
 			RawOrigin::Signed(bounty_setup.curator.clone()).into(),
@@ -287,8 +426,11 @@ benchmarks! {
 	verify {
 		assert_last_event::<T>(Event::Canceled {
 			index: bounty_setup.bounty_id,
-			child_index: bounty_setup.child_bounty_id
-		}.into())
+			child_index: bounty_setup.child_bounty_id,
+			reason: CloseReason::GovernanceClosed,
+		}.into());
+		// The deposit reserved when adding the child-bounty is refunded.
+		assert_eq!(T::Currency::reserved_balance(&bounty_setup.curator), reserved_before);
 	}
 
 	// Worst case scenario.
@@ -301,6 +443,43 @@ benchmarks! {
 		assert_last_event::<T>(Event::Canceled {
 			index: bounty_setup.bounty_id,
 			child_index: bounty_setup.child_bounty_id,
+			reason: CloseReason::GovernanceClosed,
+		}.into())
+	}
+
+	// Worst case when the child-bounty is awarded to several beneficiaries.
+	force_close_and_slash_child_curator {
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
+		ChildBounties::<T>::award_child_bounty_split(
+			RawOrigin::Signed(bounty_setup.child_curator.clone()).into(),
+			bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id,
+			split_beneficiaries::<T>(T::MaxAwardBeneficiaries::get()),
+		)?;
+		Treasury::<T>::on_initialize(T::BlockNumber::zero());
+	}: _(RawOrigin::Root, bounty_setup.bounty_id, bounty_setup.child_bounty_id)
+	verify {
+		assert_last_event::<T>(Event::Canceled {
+			index: bounty_setup.bounty_id,
+			child_index: bounty_setup.child_bounty_id,
+			reason: CloseReason::CuratorSlashed,
+		}.into())
+	}
+
+	transfer_child_curator {
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
+		let new_curator: T::AccountId = account("new-curator", 0, SEED);
+		let new_curator_lookup = T::Lookup::unlookup(new_curator.clone());
+	}: _(RawOrigin::Signed(bounty_setup.child_curator.clone()), bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id, new_curator_lookup)
+	verify {
+		assert_last_event::<T>(Event::ChildCuratorTransferred {
+			index: bounty_setup.bounty_id,
+			child_index: bounty_setup.child_bounty_id,
+			from: bounty_setup.child_curator,
+			to: new_curator,
 		}.into())
 	}
 
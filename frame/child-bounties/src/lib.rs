@@ -34,15 +34,22 @@
 //!
 //! Child Bounty protocol:
 //! - `add_child_bounty` - Add a child bounty for a parent bounty to for dividing the work in
-//!   smaller tasks.
+//!   smaller tasks, reserving a deposit for the description from the parent bounty curator.
 //! - `propose_curator` - Assign an account to a child bounty as candidate curator.
 //! - `accept_curator` - Accept a child bounty assignment from the parent bounty curator, setting a
 //!   curator deposit.
 //! - `award_child_bounty` - Close and pay out the specified amount for the completed work.
+//! - `award_child_bounty_split` - Like `award_child_bounty`, dividing the payout among several
+//!   beneficiaries.
+//! - `contribute_to_child_bounty` - Add funds to an active child bounty, increasing its payout.
 //! - `claim_child_bounty` - Claim a specific child bounty amount from the payout address.
 //! - `unassign_curator` - Unassign an accepted curator from a specific child bounty.
+//! - `child_curator_heartbeat` - Signal that the curator of a child bounty is still active,
+//!   resetting its inactivity timer.
 //! - `close_child_bounty` - Cancel the child bounty for a specific treasury amount and close the
 //!   bounty.
+//! - `force_close_and_slash_child_curator` - Close the child bounty, slashing the deposit of its
+//!   curator.
 
 // Most of the business logic in this pallet has been
 // originally contributed by "https://github.com/shamb0",
@@ -53,6 +60,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod migrations;
 mod tests;
 pub mod weights;
 
@@ -66,7 +74,7 @@ use frame_support::traits::{
 
 use sp_runtime::{
 	traits::{AccountIdConversion, BadOrigin, CheckedSub, Saturating, StaticLookup, Zero},
-	DispatchResult, RuntimeDebug,
+	DispatchResult, Perbill, Permill, RuntimeDebug,
 };
 
 use frame_support::pallet_prelude::*;
@@ -124,13 +132,96 @@ pub enum ChildBountyStatus<AccountId, BlockNumber> {
 	},
 }
 
+impl<AccountId, BlockNumber> ChildBountyStatus<AccountId, BlockNumber> {
+	/// The state of the child-bounty, without the details of the status.
+	pub fn state(&self) -> ChildBountyState {
+		match self {
+			ChildBountyStatus::Added => ChildBountyState::Added,
+			ChildBountyStatus::CuratorProposed { .. } => ChildBountyState::CuratorProposed,
+			ChildBountyStatus::Active { .. } => ChildBountyState::Active,
+			ChildBountyStatus::PendingPayout { .. } => ChildBountyState::PendingPayout,
+		}
+	}
+}
+
+/// The state of a child-bounty, as reported by `ChildBountyStatusChanged`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ChildBountyState {
+	/// The child-bounty is waiting for curator assignment.
+	Added,
+	/// A curator has been proposed and has not accepted yet.
+	CuratorProposed,
+	/// The child-bounty has a curator and is waiting to be awarded.
+	Active,
+	/// The child-bounty is awarded and waiting to be claimed.
+	PendingPayout,
+	/// The child-bounty was claimed, and no longer exists.
+	Claimed,
+	/// The child-bounty was closed, and no longer exists.
+	Canceled,
+}
+
+/// Aggregate statistics over all child bounties.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ChildBountiesStats<Balance> {
+	/// Number of child bounties waiting for curator assignment.
+	pub added: u32,
+	/// Number of child bounties with a proposed curator, who has not accepted yet.
+	pub curator_proposed: u32,
+	/// Number of child bounties with a curator, waiting to be awarded.
+	pub active: u32,
+	/// Number of awarded child bounties, waiting to be claimed.
+	pub pending_payout: u32,
+	/// Number of child bounties claimed to date.
+	pub claimed: u32,
+	/// Number of child bounties closed to date.
+	pub canceled: u32,
+	/// Sum of the values of all open child bounties.
+	pub value_locked: Balance,
+	/// Sum of the curator fees of all child bounties with an accepted curator, that are yet to
+	/// be paid out.
+	pub fees_pending: Balance,
+	/// Total paid out by claimed child bounties to date, curator fees included.
+	pub value_claimed: Balance,
+}
+
+impl<Balance> ChildBountiesStats<Balance> {
+	fn count_mut(&mut self, state: ChildBountyState) -> &mut u32 {
+		match state {
+			ChildBountyState::Added => &mut self.added,
+			ChildBountyState::CuratorProposed => &mut self.curator_proposed,
+			ChildBountyState::Active => &mut self.active,
+			ChildBountyState::PendingPayout => &mut self.pending_payout,
+			ChildBountyState::Claimed => &mut self.claimed,
+			ChildBountyState::Canceled => &mut self.canceled,
+		}
+	}
+}
+
+/// The reason a child-bounty was closed.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CloseReason {
+	/// Closed by the parent bounty curator, who proposed the child-bounty.
+	ProposerClosed,
+	/// Closed by `T::RejectOrigin`.
+	GovernanceClosed,
+	/// Closed by `T::RejectOrigin` after slashing the child-bounty curator.
+	CuratorSlashed,
+	/// Closed along with its parent bounty.
+	ParentClosed,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 
 	use super::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -145,6 +236,24 @@ pub mod pallet {
 		#[pallet::constant]
 		type ChildBountyValueMinimum: Get<BalanceOf<Self>>;
 
+		/// Maximum curator fee of a child-bounty, as a fraction of the child-bounty value.
+		#[pallet::constant]
+		type MaxCuratorFeeRatio: Get<Permill>;
+
+		/// Maximum total value of the child bounties of a parent bounty, as a fraction of the
+		/// parent bounty value.
+		#[pallet::constant]
+		type MaxChildBountyValueRatio: Get<Permill>;
+
+		/// Maximum number of beneficiaries a child-bounty award can be split among.
+		#[pallet::constant]
+		type MaxAwardBeneficiaries: Get<u32>;
+
+		/// Minimum deposit reserved by a child-bounty curator on accepting, regardless of the
+		/// child-bounty fee.
+		#[pallet::constant]
+		type ChildCuratorDepositMin: Get<BalanceOf<Self>>;
+
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -160,6 +269,13 @@ pub mod pallet {
 		InsufficientBountyBalance,
 		/// Number of child bounties exceeds limit `MaxActiveChildBountyCount`.
 		TooManyChildBounties,
+		/// Number of award beneficiaries exceeds limit `MaxAwardBeneficiaries`.
+		TooManyBeneficiaries,
+		/// The award beneficiary shares are empty or don't sum up to 100%.
+		InvalidBeneficiaryShares,
+		/// The total value of child bounties exceeds limit `MaxChildBountyValueRatio` of the
+		/// parent bounty value.
+		ExceedsParentBudget,
 	}
 
 	#[pallet::event]
@@ -168,7 +284,12 @@ pub mod pallet {
 		/// A child-bounty is added.
 		Added { index: BountyIndex, child_index: BountyIndex },
 		/// A child-bounty is awarded to a beneficiary.
-		Awarded { index: BountyIndex, child_index: BountyIndex, beneficiary: T::AccountId },
+		Awarded {
+			index: BountyIndex,
+			child_index: BountyIndex,
+			beneficiary: T::AccountId,
+			unlock_at: T::BlockNumber,
+		},
 		/// A child-bounty is claimed by beneficiary.
 		Claimed {
 			index: BountyIndex,
@@ -177,7 +298,51 @@ pub mod pallet {
 			beneficiary: T::AccountId,
 		},
 		/// A child-bounty is cancelled.
-		Canceled { index: BountyIndex, child_index: BountyIndex },
+		Canceled { index: BountyIndex, child_index: BountyIndex, reason: CloseReason },
+		/// The deposit reserved when adding a child-bounty is refunded.
+		ChildBountyDepositRefunded {
+			index: BountyIndex,
+			child_index: BountyIndex,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A child-bounty is funded by a contributor.
+		ChildBountyFunded {
+			index: BountyIndex,
+			child_index: BountyIndex,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A child-bounty curator is slashed by force.
+		ChildCuratorSlashed {
+			index: BountyIndex,
+			child_index: BountyIndex,
+			curator: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A child-bounty curator signalled they are still active.
+		ChildCuratorActive {
+			index: BountyIndex,
+			child_index: BountyIndex,
+			update_due: T::BlockNumber,
+		},
+		/// A child-bounty curator handed the role over to a successor.
+		ChildCuratorTransferred {
+			index: BountyIndex,
+			child_index: BountyIndex,
+			from: T::AccountId,
+			to: T::AccountId,
+		},
+		/// A child-bounty moved from one state to another.
+		///
+		/// Deposited before the event specific to the transition, if any. The creation of a
+		/// child-bounty is only reported by `Added`.
+		ChildBountyStatusChanged {
+			parent_id: BountyIndex,
+			child_id: BountyIndex,
+			from: ChildBountyState,
+			to: ChildBountyState,
+		},
 	}
 
 	/// Number of total child bounties.
@@ -192,6 +357,14 @@ pub mod pallet {
 	pub type ParentChildBounties<T: Config> =
 		StorageMap<_, Twox64Concat, BountyIndex, u32, ValueQuery>;
 
+	/// Total value of the child bounties of each parent bounty, contributions included.
+	///
+	/// Checked against [`Config::MaxChildBountyValueRatio`] when adding a child-bounty.
+	#[pallet::storage]
+	#[pallet::getter(fn parent_child_bounties_value)]
+	pub type ParentChildBountiesValue<T: Config> =
+		StorageMap<_, Twox64Concat, BountyIndex, BalanceOf<T>, ValueQuery>;
+
 	/// Child bounties that have been added.
 	#[pallet::storage]
 	#[pallet::getter(fn child_bounties)]
@@ -210,6 +383,49 @@ pub mod pallet {
 	pub type ChildBountyDescriptions<T: Config> =
 		StorageMap<_, Twox64Concat, BountyIndex, BoundedVec<u8, T::MaximumReasonLength>>;
 
+	/// The parent bounty of each child-bounty.
+	///
+	/// Child bounties added before this index was introduced are indexed by
+	/// [`migrations::v1::MigrateToV1`].
+	#[pallet::storage]
+	#[pallet::getter(fn parent_of_child)]
+	pub type ChildBountyParents<T: Config> = StorageMap<_, Twox64Concat, BountyIndex, BountyIndex>;
+
+	/// Aggregate statistics over all child bounties, kept up to date on every change.
+	///
+	/// Child bounties claimed or closed before these statistics were introduced are not accounted
+	/// for; see [`migrations::v1::MigrateToV1`].
+	#[pallet::storage]
+	#[pallet::getter(fn child_bounties_stats)]
+	pub type ChildBountyStats<T: Config> =
+		StorageValue<_, ChildBountiesStats<BalanceOf<T>>, ValueQuery>;
+
+	/// The account that added each child-bounty and the deposit reserved from it.
+	#[pallet::storage]
+	#[pallet::getter(fn child_bounty_deposits)]
+	pub type ChildBountyDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, BountyIndex, (T::AccountId, BalanceOf<T>)>;
+
+	/// The beneficiaries and their shares of each child-bounty awarded with
+	/// [`Pallet::award_child_bounty_split`], until it is claimed.
+	#[pallet::storage]
+	#[pallet::getter(fn child_bounty_award_splits)]
+	pub type ChildBountyAwardSplits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BountyIndex,
+		Twox64Concat,
+		BountyIndex,
+		BoundedVec<(T::AccountId, Perbill), T::MaxAwardBeneficiaries>,
+	>;
+
+	/// The block until which the curator of each active child-bounty is considered active, as
+	/// last signalled with [`Pallet::child_curator_heartbeat`].
+	#[pallet::storage]
+	#[pallet::getter(fn child_curator_update_due)]
+	pub type ChildCuratorUpdateDue<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BountyIndex, Twox64Concat, BountyIndex, T::BlockNumber>;
+
 	/// The cumulative child-bounty curator fee for each parent bounty.
 	#[pallet::storage]
 	#[pallet::getter(fn children_curator_fees)]
@@ -227,10 +443,16 @@ pub mod pallet {
 		/// parent bounty to child-bounty account, if parent bounty has enough
 		/// funds, else the call fails.
 		///
+		/// A deposit for the description is reserved from the parent bounty
+		/// curator, and refunded when the child-bounty is closed or claimed.
+		///
 		/// Upper bound to maximum number of active  child bounties that can be
 		/// added are managed via runtime trait config
 		/// [`Config::MaxActiveChildBountyCount`].
 		///
+		/// The total value of the child bounties of a parent bounty is capped at
+		/// [`Config::MaxChildBountyValueRatio`] of the parent bounty value.
+		///
 		/// If the call is success, the status of child-bounty is updated to
 		/// "Added".
 		///
@@ -247,7 +469,7 @@ pub mod pallet {
 			let signer = ensure_signed(origin)?;
 
 			// Verify the arguments.
-			let bounded_description =
+			let bounded_description: BoundedVec<_, _> =
 				description.try_into().map_err(|_| BountiesError::<T>::ReasonTooBig)?;
 			ensure!(value >= T::ChildBountyValueMinimum::get(), BountiesError::<T>::InvalidValue);
 			ensure!(
@@ -275,15 +497,34 @@ pub mod pallet {
 				new_bounty_balance,
 			)?;
 
+			// Ensure the child bounties stay within their share of the parent bounty value.
+			let parent_value = pallet_bounties::Pallet::<T>::bounties(parent_bounty_id)
+				.ok_or(BountiesError::<T>::InvalidIndex)?
+				.get_value();
+			let children_value =
+				Self::parent_child_bounties_value(parent_bounty_id).saturating_add(value);
+			ensure!(
+				children_value <= T::MaxChildBountyValueRatio::get() * parent_value,
+				Error::<T>::ExceedsParentBudget,
+			);
+
+			// Reserve the description deposit, as for parent bounties.
+			let deposit = T::BountyDepositBase::get() +
+				T::DataDepositPerByte::get() * (bounded_description.len() as u32).into();
+			T::Currency::reserve(&signer, deposit)
+				.map_err(|_| BountiesError::<T>::InsufficientProposersBalance)?;
+
 			// Get child-bounty ID.
 			let child_bounty_id = Self::child_bounty_count();
 			let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
 
 			// Transfer funds from parent bounty to child-bounty.
 			T::Currency::transfer(&parent_bounty_account, &child_bounty_account, value, KeepAlive)?;
+			ChildBountyDeposits::<T>::insert(child_bounty_id, (signer, deposit));
 
-			// Increment the active child-bounty count.
+			// Increment the active child-bounty count and value.
 			<ParentChildBounties<T>>::mutate(parent_bounty_id, |count| count.saturating_inc());
+			<ParentChildBountiesValue<T>>::insert(parent_bounty_id, children_value);
 			<ChildBountyCount<T>>::put(child_bounty_id.saturating_add(1));
 
 			// Create child-bounty instance.
@@ -310,7 +551,8 @@ pub mod pallet {
 		/// - `parent_bounty_id`: Index of parent bounty.
 		/// - `child_bounty_id`: Index of child bounty.
 		/// - `curator`: Address of child-bounty curator.
-		/// - `fee`: payment fee to child-bounty curator for execution.
+		/// - `fee`: payment fee to child-bounty curator for execution. Must not exceed
+		///   [`Config::MaxCuratorFeeRatio`] of the child-bounty value.
 		#[pallet::weight(<T as Config>::WeightInfo::propose_curator())]
 		pub fn propose_curator(
 			origin: OriginFor<T>,
@@ -341,6 +583,10 @@ pub mod pallet {
 
 					// Ensure child-bounty curator fee is less than child-bounty value.
 					ensure!(fee < child_bounty.value, BountiesError::<T>::InvalidFee);
+					ensure!(
+						fee <= T::MaxCuratorFeeRatio::get() * child_bounty.value,
+						BountiesError::<T>::InvalidFee
+					);
 
 					// Add child-bounty curator fee to the cumulative sum. To be
 					// subtracted from the parent bounty curator when claiming
@@ -355,6 +601,13 @@ pub mod pallet {
 					// Update the child-bounty state.
 					child_bounty.status =
 						ChildBountyStatus::CuratorProposed { curator: child_bounty_curator };
+					Self::note_status_change(
+						parent_bounty_id,
+						child_bounty_id,
+						child_bounty.fee,
+						ChildBountyState::Added,
+						ChildBountyState::CuratorProposed,
+					);
 
 					Ok(())
 				},
@@ -414,6 +667,13 @@ pub mod pallet {
 
 						child_bounty.status =
 							ChildBountyStatus::Active { curator: curator.clone() };
+						Self::note_status_change(
+							parent_bounty_id,
+							child_bounty_id,
+							child_bounty.fee,
+							ChildBountyState::CuratorProposed,
+							ChildBountyState::Active,
+						);
 						Ok(())
 					} else {
 						Err(BountiesError::<T>::UnexpectedStatus.into())
@@ -445,7 +705,8 @@ pub mod pallet {
 		///
 		/// Finally, the origin can be anyone iff the child-bounty curator is
 		/// "inactive". Expiry update due of parent bounty is used to estimate
-		/// inactive state of child-bounty curator.
+		/// inactive state of child-bounty curator, unless a later one was set
+		/// by the child-bounty curator with `child_curator_heartbeat`.
 		///
 		/// This allows anyone in the community to call out that a child-bounty
 		/// curator is not doing their due diligence, and we should pick a new
@@ -521,6 +782,11 @@ pub mod pallet {
 								Some(sender) => {
 									let (parent_curator, update_due) =
 										Self::ensure_bounty_active(parent_bounty_id)?;
+									let update_due = Self::child_curator_update_due(
+										parent_bounty_id,
+										child_bounty_id,
+									)
+									.map_or(update_due, |due| due.max(update_due));
 									if sender == parent_curator ||
 										update_due < frame_system::Pallet::<T>::block_number()
 									{
@@ -543,16 +809,138 @@ pub mod pallet {
 								BadOrigin,
 							);
 							slash_curator(curator, &mut child_bounty.curator_deposit);
+							// The beneficiaries of the award, if split, are void too.
+							ChildBountyAwardSplits::<T>::remove(parent_bounty_id, child_bounty_id);
 							// Continue to change child-bounty status below.
 						},
 					};
 					// Move the child-bounty state to Added.
+					let from = child_bounty.status.state();
 					child_bounty.status = ChildBountyStatus::Added;
+					ChildCuratorUpdateDue::<T>::remove(parent_bounty_id, child_bounty_id);
+					Self::note_status_change(
+						parent_bounty_id,
+						child_bounty_id,
+						child_bounty.fee,
+						from,
+						ChildBountyState::Added,
+					);
 					Ok(())
 				},
 			)
 		}
 
+		/// Contribute funds to a child-bounty, increasing its value.
+		///
+		/// The dispatch origin for this call may be any signed origin.
+		///
+		/// Parent bounty must be in active state, for this child-bounty call to
+		/// work.
+		///
+		/// Child-bounty must be in active state, i.e. have a curator and not be
+		/// awarded yet. The `amount` is transferred to the child-bounty account
+		/// and increases the payout, while the curator fee is unchanged. If the
+		/// child-bounty is closed, contributions go to the parent bounty.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `child_bounty_id`: Index of child bounty.
+		/// - `amount`: Value to contribute.
+		#[pallet::weight(<T as Config>::WeightInfo::contribute_to_child_bounty())]
+		pub fn contribute_to_child_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), BountiesError::<T>::InvalidValue);
+
+			let _ = Self::ensure_bounty_active(parent_bounty_id)?;
+
+			ChildBounties::<T>::try_mutate_exists(
+				parent_bounty_id,
+				child_bounty_id,
+				|maybe_child_bounty| -> DispatchResult {
+					let mut child_bounty =
+						maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
+
+					// Ensure child-bounty is in active state.
+					ensure!(
+						matches!(child_bounty.status, ChildBountyStatus::Active { .. }),
+						BountiesError::<T>::UnexpectedStatus,
+					);
+
+					T::Currency::transfer(
+						&signer,
+						&Self::child_bounty_account_id(child_bounty_id),
+						amount,
+						KeepAlive,
+					)?;
+					child_bounty.value = child_bounty.value.saturating_add(amount);
+					<ParentChildBountiesValue<T>>::mutate(parent_bounty_id, |value| {
+						*value = value.saturating_add(amount)
+					});
+					ChildBountyStats::<T>::mutate(|stats| {
+						stats.value_locked = stats.value_locked.saturating_add(amount)
+					});
+					Ok(())
+				},
+			)?;
+
+			Self::deposit_event(Event::<T>::ChildBountyFunded {
+				index: parent_bounty_id,
+				child_index: child_bounty_id,
+				who: signer,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Signal that the curator of a child-bounty is still active.
+		///
+		/// The dispatch origin for this call must be the curator of this
+		/// child-bounty.
+		///
+		/// Parent bounty must be in active state, for this child-bounty call to
+		/// work.
+		///
+		/// Child-bounty must be in active state. The curator inactivity timer is
+		/// reset to `BountyUpdatePeriod` blocks from now, during which the
+		/// curator cannot be unassigned by an arbitrary signed origin.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `child_bounty_id`: Index of child bounty.
+		#[pallet::weight(<T as Config>::WeightInfo::child_curator_heartbeat())]
+		pub fn child_curator_heartbeat(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			let _ = Self::ensure_bounty_active(parent_bounty_id)?;
+			let child_bounty = Self::child_bounties(parent_bounty_id, child_bounty_id)
+				.ok_or(BountiesError::<T>::InvalidIndex)?;
+
+			// Ensure child-bounty is in active state.
+			if let ChildBountyStatus::Active { ref curator } = child_bounty.status {
+				ensure!(signer == *curator, BountiesError::<T>::RequireCurator);
+			} else {
+				return Err(BountiesError::<T>::UnexpectedStatus.into())
+			}
+
+			let update_due = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::BountyUpdatePeriod::get());
+			ChildCuratorUpdateDue::<T>::insert(parent_bounty_id, child_bounty_id, update_due);
+
+			Self::deposit_event(Event::<T>::ChildCuratorActive {
+				index: parent_bounty_id,
+				child_index: child_bounty_id,
+				update_due,
+			});
+			Ok(())
+		}
+
 		/// Award child-bounty to a beneficiary.
 		///
 		/// The beneficiary will be able to claim the funds after a delay.
@@ -580,42 +968,58 @@ pub mod pallet {
 			let signer = ensure_signed(origin)?;
 			let beneficiary = T::Lookup::lookup(beneficiary)?;
 
-			// Ensure parent bounty exists, and is active.
-			let (parent_curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
+			Self::do_award(signer, parent_bounty_id, child_bounty_id, beneficiary)
+		}
 
-			ChildBounties::<T>::try_mutate_exists(
-				parent_bounty_id,
-				child_bounty_id,
-				|maybe_child_bounty| -> DispatchResult {
-					let mut child_bounty =
-						maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
+		/// Award child-bounty to several beneficiaries, each receiving a share
+		/// of the payout.
+		///
+		/// The dispatch origin for this call must be the parent curator or
+		/// curator of this child-bounty.
+		///
+		/// Works like `award_child_bounty`, with the first beneficiary acting as
+		/// the beneficiary of the child-bounty. At claim time, every beneficiary
+		/// is paid its share of the payout, with any rounding remainder going to
+		/// the first beneficiary.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `child_bounty_id`: Index of child bounty.
+		/// - `beneficiaries`: Beneficiary accounts and their shares, which must sum up to 100%.
+		///   Limited to [`Config::MaxAwardBeneficiaries`] beneficiaries.
+		#[pallet::weight(<T as Config>::WeightInfo::award_child_bounty_split(
+			beneficiaries.len() as u32
+		))]
+		pub fn award_child_bounty_split(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+			beneficiaries: Vec<(AccountIdLookupOf<T>, Perbill)>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
 
-					// Ensure child-bounty is in active state.
-					if let ChildBountyStatus::Active { ref curator } = child_bounty.status {
-						ensure!(
-							signer == *curator || signer == parent_curator,
-							BountiesError::<T>::RequireCurator,
-						);
-						// Move the child-bounty state to pending payout.
-						child_bounty.status = ChildBountyStatus::PendingPayout {
-							curator: signer,
-							beneficiary: beneficiary.clone(),
-							unlock_at: frame_system::Pallet::<T>::block_number() +
-								T::BountyDepositPayoutDelay::get(),
-						};
-						Ok(())
-					} else {
-						Err(BountiesError::<T>::UnexpectedStatus.into())
-					}
-				},
-			)?;
+			ensure!(
+				beneficiaries.len() <= T::MaxAwardBeneficiaries::get() as usize,
+				Error::<T>::TooManyBeneficiaries,
+			);
+			let total_shares = beneficiaries
+				.iter()
+				.fold(0u64, |total, (_, share)| total.saturating_add(share.deconstruct().into()));
+			ensure!(
+				total_shares == u64::from(Perbill::one().deconstruct()),
+				Error::<T>::InvalidBeneficiaryShares,
+			);
 
-			// Trigger the event Awarded.
-			Self::deposit_event(Event::<T>::Awarded {
-				index: parent_bounty_id,
-				child_index: child_bounty_id,
-				beneficiary,
-			});
+			let beneficiaries = beneficiaries
+				.into_iter()
+				.map(|(who, share)| Ok((T::Lookup::lookup(who)?, share)))
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+			let beneficiaries: BoundedVec<_, T::MaxAwardBeneficiaries> =
+				beneficiaries.try_into().map_err(|_| Error::<T>::TooManyBeneficiaries)?;
+			// Cannot be empty, as the shares sum up to 100%.
+			let beneficiary = beneficiaries[0].0.clone();
+
+			Self::do_award(signer, parent_bounty_id, child_bounty_id, beneficiary)?;
+			ChildBountyAwardSplits::<T>::insert(parent_bounty_id, child_bounty_id, beneficiaries);
 
 			Ok(())
 		}
@@ -627,23 +1031,39 @@ pub mod pallet {
 		/// Call works independent of parent bounty state, No need for parent
 		/// bounty to be in active state.
 		///
-		/// The Beneficiary is paid out with agreed bounty value. Curator fee is
-		/// paid & curator deposit is unreserved.
+		/// The Beneficiary is paid out with agreed bounty value, including any
+		/// contributions. Curator fee is paid & curator deposit is unreserved.
+		/// The deposit reserved when the child-bounty was added is refunded.
 		///
 		/// Child-bounty must be in "PendingPayout" state, for processing the
 		/// call. And instance of child-bounty is removed from the state on
 		/// successful call completion.
 		///
+		/// If the child-bounty was awarded with `award_child_bounty_split`, the
+		/// payout is divided among its beneficiaries.
+		///
 		/// - `parent_bounty_id`: Index of parent bounty.
 		/// - `child_bounty_id`: Index of child bounty.
-		#[pallet::weight(<T as Config>::WeightInfo::claim_child_bounty())]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_child_bounty()
+			.max(<T as Config>::WeightInfo::claim_child_bounty_contributed())
+			.max(<T as Config>::WeightInfo::claim_child_bounty_split(
+				T::MaxAwardBeneficiaries::get()
+			)))]
 		pub fn claim_child_bounty(
 			origin: OriginFor<T>,
 			#[pallet::compact] parent_bounty_id: BountyIndex,
 			#[pallet::compact] child_bounty_id: BountyIndex,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
 
+			let split = ChildBountyAwardSplits::<T>::take(parent_bounty_id, child_bounty_id);
+			let actual_weight = match split {
+				Some(ref beneficiaries) =>
+					<T as Config>::WeightInfo::claim_child_bounty_split(beneficiaries.len() as u32),
+				None => <T as Config>::WeightInfo::claim_child_bounty()
+					.max(<T as Config>::WeightInfo::claim_child_bounty_contributed()),
+			};
+
 			// Ensure child-bounty is in expected state.
 			ChildBounties::<T>::try_mutate_exists(
 				parent_bounty_id,
@@ -686,31 +1106,54 @@ pub mod pallet {
 						);
 						debug_assert!(fee_transfer_result.is_ok());
 
-						// Make payout to beneficiary.
-						// Should not fail.
-						let payout_transfer_result = T::Currency::transfer(
-							&child_bounty_account,
-							beneficiary,
-							payout,
-							AllowDeath,
-						);
-						debug_assert!(payout_transfer_result.is_ok());
-
-						// Trigger the Claimed event.
-						Self::deposit_event(Event::<T>::Claimed {
-							index: parent_bounty_id,
-							child_index: child_bounty_id,
-							payout,
-							beneficiary: beneficiary.clone(),
+						let payouts = match split {
+							Some(beneficiaries) => Self::split_payout(payout, beneficiaries),
+							None => vec![(beneficiary.clone(), payout)],
+						};
+						ChildBountyStats::<T>::mutate(|stats| {
+							stats.value_locked =
+								stats.value_locked.saturating_sub(child_bounty.value);
+							stats.value_claimed = stats.value_claimed.saturating_add(balance);
 						});
-
-						// Update the active child-bounty tracking count.
+						Self::note_status_change(
+							parent_bounty_id,
+							child_bounty_id,
+							child_bounty.fee,
+							ChildBountyState::PendingPayout,
+							ChildBountyState::Claimed,
+						);
+						for (beneficiary, payout) in payouts {
+							// Make payout to beneficiary.
+							// Should not fail.
+							let payout_transfer_result = T::Currency::transfer(
+								&child_bounty_account,
+								&beneficiary,
+								payout,
+								AllowDeath,
+							);
+							debug_assert!(payout_transfer_result.is_ok());
+
+							// Trigger the Claimed event.
+							Self::deposit_event(Event::<T>::Claimed {
+								index: parent_bounty_id,
+								child_index: child_bounty_id,
+								payout,
+								beneficiary,
+							});
+						}
+
+						// Update the active child-bounty tracking count and value.
 						<ParentChildBounties<T>>::mutate(parent_bounty_id, |count| {
 							count.saturating_dec()
 						});
+						<ParentChildBountiesValue<T>>::mutate(parent_bounty_id, |value| {
+							*value = value.saturating_sub(child_bounty.value)
+						});
 
-						// Remove the child-bounty description.
+						// Remove the child-bounty description and parent, and refund its deposit.
 						<ChildBountyDescriptions<T>>::remove(child_bounty_id);
+						<ChildBountyParents<T>>::remove(child_bounty_id);
+						Self::refund_deposit(parent_bounty_id, child_bounty_id);
 
 						// Remove the child-bounty instance from the state.
 						*maybe_child_bounty = None;
@@ -720,7 +1163,9 @@ pub mod pallet {
 						Err(BountiesError::<T>::UnexpectedStatus.into())
 					}
 				},
-			)
+			)?;
+
+			Ok(Some(actual_weight).into())
 		}
 
 		/// Cancel a proposed or active child-bounty. Child-bounty account funds
@@ -731,7 +1176,8 @@ pub mod pallet {
 		/// `T::RejectOrigin`.
 		///
 		/// If the state of child-bounty is `Active`, curator deposit is
-		/// unreserved.
+		/// unreserved. The deposit reserved when the child-bounty was added is
+		/// refunded in any case.
 		///
 		/// If the state of child-bounty is `PendingPayout`, call fails &
 		/// returns `PendingPayout` error.
@@ -759,11 +1205,157 @@ pub mod pallet {
 			// Ensure parent bounty exist, get parent curator.
 			let (parent_curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
 
+			let reason = if maybe_sender.is_some() {
+				CloseReason::ProposerClosed
+			} else {
+				CloseReason::GovernanceClosed
+			};
 			ensure!(maybe_sender.map_or(true, |sender| parent_curator == sender), BadOrigin);
 
-			Self::impl_close_child_bounty(parent_bounty_id, child_bounty_id)?;
+			Self::impl_close_child_bounty(parent_bounty_id, child_bounty_id, reason)?;
 			Ok(())
 		}
+
+		/// Close a child-bounty whose curator went rogue, slashing the
+		/// child-bounty curator deposit.
+		///
+		/// The dispatch origin for this call must be `T::RejectOrigin`.
+		///
+		/// Parent bounty must be in active state, for this child-bounty call to
+		/// work.
+		///
+		/// Child-bounty must be in "Active" or "PendingPayout" state. Any
+		/// award is voided, the curator deposit is slashed and child-bounty
+		/// account funds are transferred to parent bounty account. The deposit
+		/// reserved when the child-bounty was added is refunded.
+		///
+		/// Instance of child-bounty is removed from the state on successful
+		/// call completion.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `child_bounty_id`: Index of child bounty.
+		#[pallet::weight(<T as Config>::WeightInfo::force_close_and_slash_child_curator())]
+		pub fn force_close_and_slash_child_curator(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+		) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+
+			let _ = Self::ensure_bounty_active(parent_bounty_id)?;
+
+			ChildBounties::<T>::try_mutate_exists(
+				parent_bounty_id,
+				child_bounty_id,
+				|maybe_child_bounty| -> DispatchResult {
+					let mut child_bounty =
+						maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
+
+					let curator = match child_bounty.status {
+						ChildBountyStatus::Active { ref curator } |
+						ChildBountyStatus::PendingPayout { ref curator, .. } => curator.clone(),
+						_ => return Err(BountiesError::<T>::UnexpectedStatus.into()),
+					};
+
+					let amount = child_bounty.curator_deposit;
+					let imbalance = T::Currency::slash_reserved(&curator, amount).0;
+					T::OnSlash::on_unbalanced(imbalance);
+					child_bounty.curator_deposit = Zero::zero();
+
+					// The award, if any, is void. Leave the child-bounty without
+					// curator, to be closed below.
+					ChildBountyAwardSplits::<T>::remove(parent_bounty_id, child_bounty_id);
+					ChildCuratorUpdateDue::<T>::remove(parent_bounty_id, child_bounty_id);
+					let from = child_bounty.status.state();
+					child_bounty.status = ChildBountyStatus::Added;
+
+					Self::note_status_change(
+						parent_bounty_id,
+						child_bounty_id,
+						child_bounty.fee,
+						from,
+						ChildBountyState::Added,
+					);
+					Self::deposit_event(Event::<T>::ChildCuratorSlashed {
+						index: parent_bounty_id,
+						child_index: child_bounty_id,
+						curator,
+						amount,
+					});
+					Ok(())
+				},
+			)?;
+
+			Self::impl_close_child_bounty(
+				parent_bounty_id,
+				child_bounty_id,
+				CloseReason::CuratorSlashed,
+			)
+		}
+
+		/// Hand the curator role of a child-bounty over to a successor.
+		///
+		/// The dispatch origin for this call must be the child-bounty curator.
+		///
+		/// Parent bounty must be in active state, for this child-bounty call to
+		/// work.
+		///
+		/// Child-bounty must be in "Active" state. The curator deposit is
+		/// unreserved without slashing, and `new_curator` is proposed as
+		/// curator with the same fee. The successor takes over by calling
+		/// `accept_curator`, which reserves their deposit.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `child_bounty_id`: Index of child bounty.
+		/// - `new_curator`: Account to hand the curator role over to.
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_child_curator())]
+		pub fn transfer_child_curator(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+			new_curator: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let new_curator = T::Lookup::lookup(new_curator)?;
+
+			let _ = Self::ensure_bounty_active(parent_bounty_id)?;
+
+			ChildBounties::<T>::try_mutate_exists(
+				parent_bounty_id,
+				child_bounty_id,
+				|maybe_child_bounty| -> DispatchResult {
+					let mut child_bounty =
+						maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
+
+					let curator = match child_bounty.status {
+						ChildBountyStatus::Active { ref curator } => curator.clone(),
+						_ => return Err(BountiesError::<T>::UnexpectedStatus.into()),
+					};
+					ensure!(signer == curator, BountiesError::<T>::RequireCurator);
+
+					T::Currency::unreserve(&curator, child_bounty.curator_deposit);
+					child_bounty.curator_deposit = Zero::zero();
+					child_bounty.status =
+						ChildBountyStatus::CuratorProposed { curator: new_curator.clone() };
+					ChildCuratorUpdateDue::<T>::remove(parent_bounty_id, child_bounty_id);
+
+					Self::note_status_change(
+						parent_bounty_id,
+						child_bounty_id,
+						child_bounty.fee,
+						ChildBountyState::Active,
+						ChildBountyState::CuratorProposed,
+					);
+					Self::deposit_event(Event::<T>::ChildCuratorTransferred {
+						index: parent_bounty_id,
+						child_index: child_bounty_id,
+						from: curator,
+						to: new_curator,
+					});
+					Ok(())
+				},
+			)
+		}
 	}
 }
 
@@ -778,8 +1370,9 @@ impl<T: Config> Pallet<T> {
 			return Zero::zero()
 		}
 
-		// We just use the same logic from the parent bounties pallet.
+		// We just use the same logic from the parent bounties pallet, with our own floor.
 		pallet_bounties::Pallet::<T>::calculate_curator_deposit(bounty_fee)
+			.max(T::ChildCuratorDepositMin::get())
 	}
 
 	/// The account ID of a child-bounty account.
@@ -805,9 +1398,222 @@ impl<T: Config> Pallet<T> {
 		};
 		ChildBounties::<T>::insert(parent_bounty_id, child_bounty_id, &child_bounty);
 		ChildBountyDescriptions::<T>::insert(child_bounty_id, description);
+		ChildBountyParents::<T>::insert(child_bounty_id, parent_bounty_id);
+		ChildBountyStats::<T>::mutate(|stats| {
+			stats.added = stats.added.saturating_add(1);
+			stats.value_locked = stats.value_locked.saturating_add(child_bounty_value);
+		});
 		Self::deposit_event(Event::Added { index: parent_bounty_id, child_index: child_bounty_id });
 	}
 
+	// Unreserve the deposit taken when the child-bounty was added.
+	//
+	// Child bounties added before deposits were introduced have none to refund.
+	fn refund_deposit(parent_bounty_id: BountyIndex, child_bounty_id: BountyIndex) {
+		if let Some((who, amount)) = ChildBountyDeposits::<T>::take(child_bounty_id) {
+			let _ = T::Currency::unreserve(&who, amount);
+			Self::deposit_event(Event::<T>::ChildBountyDepositRefunded {
+				index: parent_bounty_id,
+				child_index: child_bounty_id,
+				who,
+				amount,
+			});
+		}
+	}
+
+	/// The `(parent_bounty_id, child_bounty_id)` of each active child-bounty curated by `who`.
+	///
+	/// This iterates over all child bounties, and is meant to be used off-chain, e.g. through the
+	/// runtime API.
+	pub fn active_child_bounties_of_curator(who: &T::AccountId) -> Vec<(BountyIndex, BountyIndex)> {
+		let mut active = ChildBounties::<T>::iter()
+			.filter_map(|(parent_bounty_id, child_bounty_id, child_bounty)| {
+				match child_bounty.status {
+					ChildBountyStatus::Active { curator } if curator == *who =>
+						Some((parent_bounty_id, child_bounty_id)),
+					_ => None,
+				}
+			})
+			.collect::<Vec<_>>();
+		active.sort();
+		active
+	}
+
+	/// The `(parent_bounty_id, child_bounty_id, beneficiary)` of up to `limit` awarded child
+	/// bounties that can be claimed at block `now`, in index order.
+	///
+	/// The beneficiary of a split award is its first beneficiary. Claimed child bounties are
+	/// removed, so once the returned ones are claimed, calling this again yields the next ones.
+	///
+	/// This iterates over all child bounties, and is meant to be used off-chain, e.g. through the
+	/// runtime API.
+	pub fn claimable_child_bounties(
+		now: T::BlockNumber,
+		limit: u32,
+	) -> Vec<(BountyIndex, BountyIndex, T::AccountId)> {
+		let mut claimable = ChildBounties::<T>::iter()
+			.filter_map(|(parent_bounty_id, child_bounty_id, child_bounty)| {
+				match child_bounty.status {
+					ChildBountyStatus::PendingPayout { beneficiary, unlock_at, .. }
+						if unlock_at <= now =>
+						Some((parent_bounty_id, child_bounty_id, beneficiary)),
+					_ => None,
+				}
+			})
+			.collect::<Vec<_>>();
+		claimable.sort_by_key(|(parent_bounty_id, child_bounty_id, _)| {
+			(*parent_bounty_id, *child_bounty_id)
+		});
+		claimable.truncate(limit as usize);
+		claimable
+	}
+
+	fn do_award(
+		signer: T::AccountId,
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+		beneficiary: T::AccountId,
+	) -> DispatchResult {
+		// Ensure parent bounty exists, and is active.
+		let (parent_curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
+		let unlock_at =
+			frame_system::Pallet::<T>::block_number() + T::BountyDepositPayoutDelay::get();
+
+		ChildBounties::<T>::try_mutate_exists(
+			parent_bounty_id,
+			child_bounty_id,
+			|maybe_child_bounty| -> DispatchResult {
+				let mut child_bounty =
+					maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
+
+				// Ensure child-bounty is in active state.
+				if let ChildBountyStatus::Active { ref curator } = child_bounty.status {
+					ensure!(
+						signer == *curator || signer == parent_curator,
+						BountiesError::<T>::RequireCurator,
+					);
+					// Move the child-bounty state to pending payout.
+					child_bounty.status = ChildBountyStatus::PendingPayout {
+						curator: signer,
+						beneficiary: beneficiary.clone(),
+						unlock_at,
+					};
+					Self::note_status_change(
+						parent_bounty_id,
+						child_bounty_id,
+						child_bounty.fee,
+						ChildBountyState::Active,
+						ChildBountyState::PendingPayout,
+					);
+					Ok(())
+				} else {
+					Err(BountiesError::<T>::UnexpectedStatus.into())
+				}
+			},
+		)?;
+		ChildCuratorUpdateDue::<T>::remove(parent_bounty_id, child_bounty_id);
+
+		// Trigger the event Awarded.
+		Self::deposit_event(Event::<T>::Awarded {
+			index: parent_bounty_id,
+			child_index: child_bounty_id,
+			beneficiary,
+			unlock_at,
+		});
+
+		Ok(())
+	}
+
+	// Account for a child-bounty with curator `fee` moving from one state to another in the
+	// statistics, and report it.
+	fn note_status_change(
+		parent_id: BountyIndex,
+		child_id: BountyIndex,
+		fee: BalanceOf<T>,
+		from: ChildBountyState,
+		to: ChildBountyState,
+	) {
+		let earns_fee =
+			|state| matches!(state, ChildBountyState::Active | ChildBountyState::PendingPayout);
+		ChildBountyStats::<T>::mutate(|stats| {
+			let count = stats.count_mut(from);
+			*count = count.saturating_sub(1);
+			let count = stats.count_mut(to);
+			*count = count.saturating_add(1);
+			match (earns_fee(from), earns_fee(to)) {
+				(false, true) => stats.fees_pending = stats.fees_pending.saturating_add(fee),
+				(true, false) => stats.fees_pending = stats.fees_pending.saturating_sub(fee),
+				_ => (),
+			}
+		});
+		Self::deposit_event(Event::<T>::ChildBountyStatusChanged { parent_id, child_id, from, to });
+	}
+
+	/// Divide `payout` among `beneficiaries` according to their shares.
+	///
+	/// Shares are rounded down, with the remainder going to the first beneficiary.
+	fn split_payout(
+		payout: BalanceOf<T>,
+		beneficiaries: BoundedVec<(T::AccountId, Perbill), T::MaxAwardBeneficiaries>,
+	) -> Vec<(T::AccountId, BalanceOf<T>)> {
+		let mut payouts = beneficiaries
+			.into_iter()
+			.map(|(who, share)| (who, share.mul_floor(payout)))
+			.collect::<Vec<_>>();
+		let distributed = payouts
+			.iter()
+			.fold(Zero::zero(), |total: BalanceOf<T>, (_, p)| total.saturating_add(*p));
+		if let Some((_, first)) = payouts.first_mut() {
+			*first = first.saturating_add(payout.saturating_sub(distributed));
+		}
+		payouts
+	}
+
+	/// The block from which the awarded child-bounty `child_bounty_id` of `parent_bounty_id` can
+	/// be claimed, or `None` if it is not pending payout.
+	pub fn child_bounty_unlock_at(
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+	) -> Option<T::BlockNumber> {
+		match ChildBounties::<T>::get(parent_bounty_id, child_bounty_id)?.status {
+			ChildBountyStatus::PendingPayout { unlock_at, .. } => Some(unlock_at),
+			_ => None,
+		}
+	}
+
+	/// The free balance of the account of child-bounty `child_bounty_id` of `parent_bounty_id`,
+	/// or zero if there is no such child-bounty.
+	///
+	/// This may differ from the recorded value of the child-bounty, e.g. if funds were sent to
+	/// its account directly.
+	pub fn child_bounty_account_balance(
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+	) -> BalanceOf<T> {
+		if !ChildBounties::<T>::contains_key(parent_bounty_id, child_bounty_id) {
+			return Zero::zero()
+		}
+		T::Currency::free_balance(&Self::child_bounty_account_id(child_bounty_id))
+	}
+
+	/// The sum of the curator fees of all child bounties with an accepted curator, that are yet
+	/// to be paid out.
+	///
+	/// This iterates over all child bounties, and is meant to be used off-chain, e.g. through the
+	/// runtime API.
+	pub fn pending_child_curator_fees() -> BalanceOf<T> {
+		ChildBounties::<T>::iter_values()
+			.filter(|child_bounty| {
+				matches!(
+					child_bounty.status,
+					ChildBountyStatus::Active { .. } | ChildBountyStatus::PendingPayout { .. }
+				)
+			})
+			.fold(Zero::zero(), |total: BalanceOf<T>, child_bounty| {
+				total.saturating_add(child_bounty.fee)
+			})
+	}
+
 	fn ensure_bounty_active(
 		bounty_id: BountyIndex,
 	) -> Result<(T::AccountId, T::BlockNumber), DispatchError> {
@@ -823,6 +1629,7 @@ impl<T: Config> Pallet<T> {
 	fn impl_close_child_bounty(
 		parent_bounty_id: BountyIndex,
 		child_bounty_id: BountyIndex,
+		reason: CloseReason,
 	) -> DispatchResult {
 		ChildBounties::<T>::try_mutate_exists(
 			parent_bounty_id,
@@ -839,6 +1646,7 @@ impl<T: Config> Pallet<T> {
 						// Cancelled by parent curator or RejectOrigin,
 						// refund deposit of the working child-bounty curator.
 						let _ = T::Currency::unreserve(curator, child_bounty.curator_deposit);
+						ChildCuratorUpdateDue::<T>::remove(parent_bounty_id, child_bounty_id);
 						// Then execute removal of the child-bounty below.
 					},
 					ChildBountyStatus::PendingPayout { .. } => {
@@ -852,15 +1660,20 @@ impl<T: Config> Pallet<T> {
 				}
 
 				// Revert the curator fee back to parent bounty curator &
-				// reduce the active child-bounty count.
+				// reduce the active child-bounty count and value.
 				ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |value| {
 					*value = value.saturating_sub(child_bounty.fee)
 				});
 				<ParentChildBounties<T>>::mutate(parent_bounty_id, |count| {
 					*count = count.saturating_sub(1)
 				});
+				<ParentChildBountiesValue<T>>::mutate(parent_bounty_id, |value| {
+					*value = value.saturating_sub(child_bounty.value)
+				});
 
-				// Transfer fund from child-bounty to parent bounty.
+				// Transfer fund from child-bounty to parent bounty. The whole free balance is
+				// swept, including any dust sent to the child-bounty account, and the account is
+				// allowed to be reaped so nothing is left behind.
 				let parent_bounty_account =
 					pallet_bounties::Pallet::<T>::bounty_account_id(parent_bounty_id);
 				let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
@@ -873,14 +1686,28 @@ impl<T: Config> Pallet<T> {
 				); // Should not fail; child bounty account gets this balance during creation.
 				debug_assert!(transfer_result.is_ok());
 
-				// Remove the child-bounty description.
+				// Remove the child-bounty description and parent, and refund its deposit.
 				<ChildBountyDescriptions<T>>::remove(child_bounty_id);
+				<ChildBountyParents<T>>::remove(child_bounty_id);
+				Self::refund_deposit(parent_bounty_id, child_bounty_id);
+
+				ChildBountyStats::<T>::mutate(|stats| {
+					stats.value_locked = stats.value_locked.saturating_sub(child_bounty.value)
+				});
+				Self::note_status_change(
+					parent_bounty_id,
+					child_bounty_id,
+					child_bounty.fee,
+					child_bounty.status.state(),
+					ChildBountyState::Canceled,
+				);
 
 				*maybe_child_bounty = None;
 
 				Self::deposit_event(Event::<T>::Canceled {
 					index: parent_bounty_id,
 					child_index: child_bounty_id,
+					reason,
 				});
 				Ok(())
 			},
@@ -905,4 +1732,33 @@ impl<T: Config> pallet_bounties::ChildBountyManager<BalanceOf<T>> for Pallet<T>
 		<ChildrenCuratorFees<T>>::remove(bounty_id);
 		children_fee_total
 	}
+
+	fn on_parent_closed(bounty_id: pallet_bounties::BountyIndex) -> Weight {
+		// There are at most `MaxActiveChildBountyCount` child bounties, the bound is only here to
+		// keep the weight of closing the parent bounty bounded.
+		let mut children = ChildBounties::<T>::iter_key_prefix(bounty_id)
+			.take(T::MaxActiveChildBountyCount::get() as usize)
+			.collect::<Vec<_>>();
+		children.sort();
+		let mut canceled = 0;
+		for child_bounty_id in &children {
+			// Child bounties pending payout can't be canceled, and keep the parent open.
+			if Self::impl_close_child_bounty(bounty_id, *child_bounty_id, CloseReason::ParentClosed)
+				.is_ok()
+			{
+				canceled += 1;
+			}
+		}
+
+		<T as Config>::WeightInfo::close_child_bounty_active()
+			.saturating_mul(canceled)
+			.saturating_add(T::DbWeight::get().reads(children.len() as u64 + 1))
+	}
+
+	fn on_parent_closed_max_weight() -> Weight {
+		let max_children = T::MaxActiveChildBountyCount::get() as u64;
+		<T as Config>::WeightInfo::close_child_bounty_active()
+			.saturating_mul(max_children)
+			.saturating_add(T::DbWeight::get().reads(max_children + 1))
+	}
 }
@@ -0,0 +1,124 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Migrations for Child Bounties Pallet
+
+use super::*;
+use frame_support::{dispatch::GetStorageVersion, traits::OnRuntimeUpgrade};
+use sp_std::collections::btree_map::BTreeMap;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+
+pub mod v1 {
+	use super::*;
+
+	/// Builds [`ChildBountyStats`], [`ChildBountyParents`] and [`ParentChildBountiesValue`] from
+	/// the child bounties open at the time of the upgrade.
+	///
+	/// Claimed and closed child bounties are no longer in storage, so `claimed`, `canceled` and
+	/// `value_claimed` only account for those claimed or closed after the upgrade.
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			ensure!(onchain < 1, "this migration can be deleted");
+
+			Ok((ChildBounties::<T>::iter().count() as u32).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let current = Pallet::<T>::current_storage_version();
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain > 0 {
+				log::info!(target: "runtime::child-bounties", "MigrateToV1 should be removed");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut stats = ChildBountiesStats::<BalanceOf<T>>::default();
+			let mut parent_values = BTreeMap::<BountyIndex, BalanceOf<T>>::new();
+			let mut count = 0u64;
+			for (parent_bounty_id, child_bounty_id, child_bounty) in ChildBounties::<T>::iter() {
+				count += 1;
+				ChildBountyParents::<T>::insert(child_bounty_id, parent_bounty_id);
+				let parent_value = parent_values.entry(parent_bounty_id).or_insert_with(Zero::zero);
+				*parent_value = parent_value.saturating_add(child_bounty.value);
+				let state = child_bounty.status.state();
+				let state_count = stats.count_mut(state);
+				*state_count = state_count.saturating_add(1);
+				stats.value_locked = stats.value_locked.saturating_add(child_bounty.value);
+				if matches!(state, ChildBountyState::Active | ChildBountyState::PendingPayout) {
+					stats.fees_pending = stats.fees_pending.saturating_add(child_bounty.fee);
+				}
+			}
+			ChildBountyStats::<T>::put(stats);
+			let parents = parent_values.len() as u64;
+			for (parent_bounty_id, value) in parent_values {
+				ParentChildBountiesValue::<T>::insert(parent_bounty_id, value);
+			}
+
+			current.put::<Pallet<T>>();
+
+			log::info!(
+				target: "runtime::child-bounties",
+				"Built child-bounty statistics and parents from {} open child bounties",
+				count,
+			);
+
+			T::DbWeight::get().reads_writes(
+				count.saturating_add(1),
+				count.saturating_add(parents).saturating_add(2),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let open: u32 = Decode::decode(&mut &state[..]).map_err(|_| {
+				"the state parameter should be something that was generated by pre_upgrade"
+			})?;
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			ensure!(onchain < 2, "this migration needs to be removed");
+			ensure!(onchain == 1, "this migration needs to be run");
+			let stats = ChildBountyStats::<T>::get();
+			ensure!(
+				stats.added + stats.curator_proposed + stats.active + stats.pending_payout == open,
+				"every open child-bounty should be counted"
+			);
+			for (parent_bounty_id, child_bounty_id) in ChildBounties::<T>::iter_keys() {
+				ensure!(
+					ChildBountyParents::<T>::get(child_bounty_id) == Some(parent_bounty_id),
+					"every open child-bounty should have its parent indexed"
+				);
+			}
+			let mut parent_values = BTreeMap::<BountyIndex, BalanceOf<T>>::new();
+			for (parent_bounty_id, _, child_bounty) in ChildBounties::<T>::iter() {
+				let parent_value = parent_values.entry(parent_bounty_id).or_insert_with(Zero::zero);
+				*parent_value = parent_value.saturating_add(child_bounty.value);
+			}
+			for (parent_bounty_id, value) in parent_values {
+				ensure!(
+					ParentChildBountiesValue::<T>::get(parent_bounty_id) == value,
+					"the value of the child bounties of every parent should be tracked"
+				);
+			}
+			Ok(())
+		}
+	}
+}
@@ -133,7 +133,9 @@ parameter_types! {
 	pub const CuratorDepositMultiplier: Permill = Permill::from_percent(50);
 	pub const CuratorDepositMax: Balance = 1_000;
 	pub const CuratorDepositMin: Balance = 3;
-
+	pub const MaxCuratorFeeRatio: Permill = Permill::from_percent(80);
+	pub static MaxChildBountyValueRatio: Permill = Permill::one();
+	pub static ChildCuratorDepositMin: Balance = 0;
 }
 impl pallet_bounties::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -153,6 +155,10 @@ impl pallet_child_bounties::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = ConstU32<2>;
 	type ChildBountyValueMinimum = ConstU64<1>;
+	type MaxCuratorFeeRatio = MaxCuratorFeeRatio;
+	type MaxChildBountyValueRatio = MaxChildBountyValueRatio;
+	type MaxAwardBeneficiaries = ConstU32<3>;
+	type ChildCuratorDepositMin = ChildCuratorDepositMin;
 	type WeightInfo = ();
 }
 
@@ -266,8 +272,11 @@ fn add_child_bounty() {
 		// Check for the event child-bounty added.
 		assert_eq!(last_event(), ChildBountiesEvent::Added { index: 0, child_index: 0 });
 
-		assert_eq!(Balances::free_balance(4), 101);
-		assert_eq!(Balances::reserved_balance(4), expected_deposit);
+		// The description deposit is reserved from the parent curator.
+		let child_bounty_deposit = 80 + 8; // BountyDepositBase + DataDepositPerByte * 8
+		assert_eq!(Balances::free_balance(4), 101 - child_bounty_deposit);
+		assert_eq!(Balances::reserved_balance(4), expected_deposit + child_bounty_deposit);
+		assert_eq!(ChildBounties::child_bounty_deposits(0), Some((4, child_bounty_deposit)));
 
 		// DB check.
 		// Check the child-bounty status.
@@ -290,6 +299,44 @@ fn add_child_bounty() {
 	});
 }
 
+#[test]
+fn add_child_bounty_respects_parent_budget() {
+	new_test_ext().execute_with(|| {
+		MaxChildBountyValueRatio::set(Permill::from_percent(50));
+
+		// Make the parent bounty, worth 50.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 8));
+		Balances::make_free_balance_be(&4, 1000);
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Children up to half of the parent value are fine.
+		assert_ok!(ChildBounties::add_child_bounty(RuntimeOrigin::signed(4), 0, 10, b"c".to_vec()));
+		assert_ok!(ChildBounties::add_child_bounty(RuntimeOrigin::signed(4), 0, 15, b"c".to_vec()));
+		assert_eq!(ChildBounties::parent_child_bounties_value(0), 25);
+
+		// Anything more exceeds the budget.
+		assert_noop!(
+			ChildBounties::add_child_bounty(RuntimeOrigin::signed(4), 0, 1, b"c".to_vec()),
+			Error::<Test>::ExceedsParentBudget,
+		);
+
+		// Closing a child frees its share of the budget.
+		assert_ok!(ChildBounties::close_child_bounty(RuntimeOrigin::signed(4), 0, 0));
+		assert_eq!(ChildBounties::parent_child_bounties_value(0), 15);
+		assert_ok!(ChildBounties::add_child_bounty(RuntimeOrigin::signed(4), 0, 10, b"c".to_vec()));
+		assert_noop!(
+			ChildBounties::add_child_bounty(RuntimeOrigin::signed(4), 0, 1, b"c".to_vec()),
+			Error::<Test>::ExceedsParentBudget,
+		);
+	});
+}
+
 #[test]
 fn child_bounty_assign_curator() {
 	new_test_ext().execute_with(|| {
@@ -358,9 +405,10 @@ fn child_bounty_assign_curator() {
 			}
 		);
 
-		// Check the balance of parent curator.
-		assert_eq!(Balances::free_balance(4), 101 - expected_deposit);
-		assert_eq!(Balances::reserved_balance(4), expected_deposit);
+		// Check the balance of parent curator, who also reserved the child-bounty deposit.
+		let child_bounty_deposit = 80 + 8; // BountyDepositBase + DataDepositPerByte * 8
+		assert_eq!(Balances::free_balance(4), 101 - expected_deposit - child_bounty_deposit);
+		assert_eq!(Balances::reserved_balance(4), expected_deposit + child_bounty_deposit);
 
 		assert_noop!(
 			ChildBounties::accept_curator(RuntimeOrigin::signed(3), 0, 0),
@@ -515,6 +563,9 @@ fn close_child_bounty_added() {
 
 		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
 
+		let free_before = Balances::free_balance(4);
+		let reserved_before = Balances::reserved_balance(4);
+
 		// Child-bounty.
 		assert_ok!(ChildBounties::add_child_bounty(
 			RuntimeOrigin::signed(4),
@@ -525,6 +576,11 @@ fn close_child_bounty_added() {
 
 		assert_eq!(last_event(), ChildBountiesEvent::Added { index: 0, child_index: 0 });
 
+		// The parent curator reserved the child-bounty deposit.
+		let child_bounty_deposit = 80 + 8; // BountyDepositBase + DataDepositPerByte * 8
+		assert_eq!(Balances::free_balance(4), free_before - child_bounty_deposit);
+		assert_eq!(Balances::reserved_balance(4), reserved_before + child_bounty_deposit);
+
 		System::set_block_number(4);
 
 		// Close child-bounty.
@@ -534,6 +590,24 @@ fn close_child_bounty_added() {
 
 		// Correct origin - parent curator.
 		assert_ok!(ChildBounties::close_child_bounty(RuntimeOrigin::signed(4), 0, 0));
+		assert!(System::events().iter().any(|e| e.event ==
+			RuntimeEvent::ChildBounties(ChildBountiesEvent::Canceled {
+				index: 0,
+				child_index: 0,
+				reason: CloseReason::ProposerClosed,
+			})));
+
+		// The deposit is refunded, as no curator ever accepted the child-bounty.
+		assert!(System::events().iter().any(|e| e.event ==
+			RuntimeEvent::ChildBounties(ChildBountiesEvent::ChildBountyDepositRefunded {
+				index: 0,
+				child_index: 0,
+				who: 4,
+				amount: child_bounty_deposit,
+			})));
+		assert_eq!(Balances::free_balance(4), free_before);
+		assert_eq!(Balances::reserved_balance(4), reserved_before);
+		assert_eq!(ChildBounties::child_bounty_deposits(0), None);
 
 		// Check the child-bounty count.
 		assert_eq!(ChildBounties::parent_child_bounties(0), 0);
@@ -667,6 +741,69 @@ fn close_child_bounty_pending() {
 	});
 }
 
+#[test]
+fn child_curator_deposit_has_a_floor() {
+	new_test_ext().execute_with(|| {
+		ChildCuratorDepositMin::set(5);
+
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		// Parent-bounty curator, paying the deposits of two child bounties.
+		Balances::make_free_balance_be(&4, 301);
+		Balances::make_free_balance_be(&7, 101); // Curator of the low-fee child-bounty.
+		Balances::make_free_balance_be(&8, 101); // Curator of the high-fee child-bounty.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			30,
+			b"12345-p2".to_vec()
+		));
+
+		// A tiny fee: 50% of it, even raised to `CuratorDepositMin`, is below the floor.
+		let child_fee = 2;
+		assert!(CuratorDepositMultiplier::get() * child_fee < ChildCuratorDepositMin::get());
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 7, child_fee));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(7), 0, 0));
+
+		// Then the floor is reserved.
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap().curator_deposit, 5);
+		assert_eq!(Balances::reserved_balance(7), 5);
+		assert_eq!(Balances::free_balance(7), 101 - 5);
+
+		// A large fee: 50% of it is above the floor.
+		let child_fee = 20;
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 1, 8, child_fee));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 1));
+
+		// Then the proportional deposit is reserved.
+		let expected_child_deposit = CuratorDepositMultiplier::get() * child_fee;
+		assert_eq!(expected_child_deposit, 10);
+		assert_eq!(
+			ChildBounties::child_bounties(0, 1).unwrap().curator_deposit,
+			expected_child_deposit
+		);
+		assert_eq!(Balances::reserved_balance(8), expected_child_deposit);
+		assert_eq!(Balances::free_balance(8), 101 - expected_child_deposit);
+	});
+}
+
 #[test]
 fn child_bounty_added_unassign_curator() {
 	new_test_ext().execute_with(|| {
@@ -1182,16 +1319,28 @@ fn close_parent_with_child_bounty() {
 		<Treasury as OnInitialize<u64>>::on_initialize(4);
 
 		// Try close parent-bounty.
-		// Child bounty active, can't close parent.
+		// Child bounty pending payout can't be canceled, so can't close parent.
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
 		assert_noop!(
 			Bounties::close_bounty(RuntimeOrigin::root(), 0),
 			BountiesError::HasActiveChildBounty
 		);
+		assert_ok!(ChildBounties::unassign_curator(RuntimeOrigin::root(), 0, 0));
 
 		System::set_block_number(2);
 
 		// Close child-bounty.
 		assert_ok!(ChildBounties::close_child_bounty(RuntimeOrigin::root(), 0, 0));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::Canceled {
+				index: 0,
+				child_index: 0,
+				reason: CloseReason::GovernanceClosed
+			}
+		);
 
 		// Check the child-bounty count.
 		assert_eq!(ChildBounties::parent_child_bounties(0), 0);
@@ -1202,6 +1351,94 @@ fn close_parent_with_child_bounty() {
 	});
 }
 
+#[test]
+fn close_parent_cancels_active_child_bounties() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		// Parent-bounty curator, paying the deposits of two child bounties.
+		Balances::make_free_balance_be(&4, 301);
+		Balances::make_free_balance_be(&7, 101); // Child-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Two active child bounties.
+		for (child_bounty_id, curator, value) in [(0, 7, 10), (1, 8, 20)] {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				value,
+				b"12345-p1".to_vec()
+			));
+			assert_ok!(ChildBounties::propose_curator(
+				RuntimeOrigin::signed(4),
+				0,
+				child_bounty_id,
+				curator,
+				2
+			));
+			assert_ok!(ChildBounties::accept_curator(
+				RuntimeOrigin::signed(curator),
+				0,
+				child_bounty_id
+			));
+		}
+		assert_eq!(ChildBounties::parent_child_bounties(0), 2);
+		assert!(Balances::reserved_balance(4) > 0);
+		assert!(Balances::reserved_balance(7) > 0);
+		assert!(Balances::reserved_balance(8) > 0);
+		let treasury_balance = Balances::free_balance(Treasury::account_id());
+
+		// When the parent bounty is closed.
+		assert_ok!(Bounties::close_bounty(RuntimeOrigin::root(), 0));
+
+		// Then both child bounties are canceled.
+		let canceled = System::events()
+			.into_iter()
+			.filter_map(|r| match r.event {
+				RuntimeEvent::ChildBounties(ChildBountiesEvent::Canceled {
+					index,
+					child_index,
+					reason,
+				}) => Some((index, child_index, reason)),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(
+			canceled,
+			vec![(0, 0, CloseReason::ParentClosed), (0, 1, CloseReason::ParentClosed)]
+		);
+		assert_eq!(ChildBounties::parent_child_bounties(0), 0);
+		assert_eq!(ChildBounties::child_bounties(0, 0), None);
+		assert_eq!(ChildBounties::child_bounties(0, 1), None);
+		assert_eq!(Bounties::bounties(0), None);
+
+		// The whole parent bounty value, including that of the children, goes to the treasury.
+		assert_eq!(Balances::free_balance(Treasury::account_id()), treasury_balance + 50);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 0);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(1)), 0);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+
+		// The deposits of the child-bounty proposer and curators are refunded.
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::free_balance(4), 301);
+		assert_eq!(Balances::reserved_balance(7), 0);
+		assert_eq!(Balances::free_balance(7), 101);
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(Balances::free_balance(8), 101);
+	});
+}
+
 #[test]
 fn children_curator_fee_calculation_test() {
 	// Tests the calculation of subtracting child-bounty curator fee
@@ -1390,9 +1627,13 @@ fn accept_curator_handles_different_deposit_calculations() {
 			child_index
 		));
 
-		// No expected deposit
-		assert_eq!(Balances::free_balance(child_curator), free_before);
-		assert_eq!(Balances::reserved_balance(child_curator), reserved_before);
+		// No expected curator deposit, only the deposit for adding the child-bounty.
+		let child_bounty_deposit = 80 + 8; // BountyDepositBase + DataDepositPerByte * 8
+		assert_eq!(Balances::free_balance(child_curator), free_before - child_bounty_deposit);
+		assert_eq!(
+			Balances::reserved_balance(child_curator),
+			reserved_before + child_bounty_deposit
+		);
 
 		// Case 3: Upper Limit
 
@@ -1428,7 +1669,11 @@ fn accept_curator_handles_different_deposit_calculations() {
 		assert_eq!(Balances::reserved_balance(child_curator), expected_deposit);
 
 		// There is a max number of child bounties at a time.
-		assert_ok!(ChildBounties::impl_close_child_bounty(parent_index, child_index));
+		assert_ok!(ChildBounties::impl_close_child_bounty(
+			parent_index,
+			child_index,
+			CloseReason::GovernanceClosed
+		));
 
 		// Case 4: Lower Limit
 
@@ -1464,3 +1709,1099 @@ fn accept_curator_handles_different_deposit_calculations() {
 		assert_eq!(Balances::reserved_balance(child_curator), expected_deposit);
 	});
 }
+
+#[test]
+fn active_child_bounties_of_curator_works() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 301);
+		Balances::make_free_balance_be(&8, 101);
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Acc-8 is proposed as curator of two child bounties.
+		for child_bounty_id in 0..2 {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				b"12345-p1".to_vec()
+			));
+			assert_ok!(ChildBounties::propose_curator(
+				RuntimeOrigin::signed(4),
+				0,
+				child_bounty_id,
+				8,
+				2
+			));
+		}
+
+		// Proposed curators do not count until they accept.
+		assert!(ChildBounties::active_child_bounties_of_curator(&8).is_empty());
+
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 1));
+
+		assert_eq!(ChildBounties::active_child_bounties_of_curator(&8), vec![(0, 0), (0, 1)]);
+		assert!(ChildBounties::active_child_bounties_of_curator(&4).is_empty());
+
+		// Giving up one child-bounty removes it from the workload.
+		assert_ok!(ChildBounties::unassign_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_eq!(ChildBounties::active_child_bounties_of_curator(&8), vec![(0, 1)]);
+	});
+}
+
+#[test]
+fn close_child_bounty_sweeps_leftover_balance() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 301);
+		Balances::make_free_balance_be(&8, 101);
+		Balances::make_free_balance_be(&9, 101);
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		let parent_account = Bounties::bounty_account_id(0);
+		for child_bounty_id in 0..2 {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				b"12345-p1".to_vec()
+			));
+			// The second child-bounty is closed while active.
+			if child_bounty_id == 1 {
+				assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 1, 8, 2));
+				assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 1));
+			}
+
+			// Some dust is sent to the child-bounty account.
+			let child_account = ChildBounties::child_bounty_account_id(child_bounty_id);
+			assert_ok!(Balances::transfer(RuntimeOrigin::signed(9), child_account, 1));
+			assert_eq!(Balances::free_balance(child_account), 11);
+			let parent_before = Balances::free_balance(parent_account);
+
+			assert_ok!(ChildBounties::close_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				child_bounty_id
+			));
+
+			// Nothing is left behind, and the parent bounty gets it all back.
+			assert_eq!(Balances::total_balance(&child_account), 0);
+			assert!(!System::account_exists(&child_account));
+			assert_eq!(Balances::free_balance(parent_account), parent_before + 11);
+		}
+	});
+}
+
+#[test]
+fn child_bounty_account_balance_works() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+		Balances::make_free_balance_be(&9, 101); // Contributor.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		assert_eq!(ChildBounties::child_bounty_account_balance(0, 0), 0);
+
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+
+		// A fresh child-bounty holds exactly its value.
+		assert_eq!(ChildBounties::child_bounty_account_balance(0, 0), 10);
+		// The child-bounty is only found under its own parent.
+		assert_eq!(ChildBounties::child_bounty_account_balance(1, 0), 0);
+
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::contribute_to_child_bounty(RuntimeOrigin::signed(9), 0, 0, 5));
+		assert_eq!(ChildBounties::child_bounty_account_balance(0, 0), 15);
+
+		// Funds sent directly to the account are not part of the recorded value.
+		let child_account = ChildBounties::child_bounty_account_id(0);
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(9), child_account, 1));
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap().value, 15);
+		assert_eq!(ChildBounties::child_bounty_account_balance(0, 0), 16);
+	});
+}
+
+#[test]
+fn pending_child_curator_fees_works() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 301);
+		Balances::make_free_balance_be(&8, 101);
+		Balances::make_free_balance_be(&9, 101);
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Acc-8 and acc-9 are proposed as curators with different fees.
+		for (child_bounty_id, curator, fee) in [(0, 8, 2), (1, 9, 3)] {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				b"12345-p1".to_vec()
+			));
+			assert_ok!(ChildBounties::propose_curator(
+				RuntimeOrigin::signed(4),
+				0,
+				child_bounty_id,
+				curator,
+				fee
+			));
+		}
+
+		// Proposed curators do not count until they accept.
+		assert_eq!(ChildBounties::pending_child_curator_fees(), 0);
+
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(9), 0, 1));
+		assert_eq!(ChildBounties::pending_child_curator_fees(), 5);
+
+		// Awarded child bounties are still to be paid out.
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(9), 0, 1, 7));
+		assert_eq!(ChildBounties::pending_child_curator_fees(), 5);
+
+		System::set_block_number(9);
+
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+		assert_eq!(ChildBounties::pending_child_curator_fees(), 3);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 1));
+		assert_eq!(ChildBounties::pending_child_curator_fees(), 0);
+	});
+}
+
+#[test]
+fn propose_curator_respects_max_fee_ratio() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 301);
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		for _ in 0..2 {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				b"12345-p1".to_vec()
+			));
+		}
+
+		// The maximum fee is 80% of the child-bounty value.
+		assert_eq!(MaxCuratorFeeRatio::get() * 10u64, 8);
+
+		// Just above the cap.
+		assert_noop!(
+			ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 9),
+			BountiesError::InvalidFee
+		);
+
+		// At the cap.
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 8));
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap().fee, 8);
+
+		// Well below the cap.
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 1, 8, 2));
+		assert_eq!(ChildBounties::child_bounties(0, 1).unwrap().fee, 2);
+	});
+}
+
+#[test]
+fn awarded_event_reports_unlock_block() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101);
+		Balances::make_free_balance_be(&8, 101);
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_eq!(ChildBounties::child_bounty_unlock_at(0, 0), None);
+
+		System::set_block_number(5);
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
+
+		// `BountyDepositPayoutDelay` is 3 blocks.
+		let unlock_at = 5 + 3;
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::Awarded { index: 0, child_index: 0, beneficiary: 7, unlock_at }
+		);
+		assert_eq!(ChildBounties::child_bounty_unlock_at(0, 0), Some(unlock_at));
+
+		// The child-bounty cannot be claimed before the reported block...
+		System::set_block_number(unlock_at - 1);
+		assert_noop!(
+			ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0),
+			BountiesError::Premature
+		);
+
+		// ...but can be claimed from it on.
+		System::set_block_number(unlock_at);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+		assert_eq!(ChildBounties::child_bounty_unlock_at(0, 0), None);
+	});
+}
+
+#[test]
+fn award_child_bounty_split_works() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101);
+		Balances::make_free_balance_be(&8, 101);
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+
+		// The shares must sum up to 100%.
+		assert_noop!(
+			ChildBounties::award_child_bounty_split(RuntimeOrigin::signed(8), 0, 0, vec![]),
+			Error::<Test>::InvalidBeneficiaryShares
+		);
+		assert_noop!(
+			ChildBounties::award_child_bounty_split(
+				RuntimeOrigin::signed(8),
+				0,
+				0,
+				vec![(7, Perbill::from_percent(50)), (9, Perbill::from_percent(40))]
+			),
+			Error::<Test>::InvalidBeneficiaryShares
+		);
+		// At most `MaxAwardBeneficiaries` beneficiaries.
+		assert_noop!(
+			ChildBounties::award_child_bounty_split(
+				RuntimeOrigin::signed(8),
+				0,
+				0,
+				vec![
+					(7, Perbill::from_percent(25)),
+					(9, Perbill::from_percent(25)),
+					(10, Perbill::from_percent(25)),
+					(11, Perbill::from_percent(25))
+				]
+			),
+			Error::<Test>::TooManyBeneficiaries
+		);
+
+		assert_ok!(ChildBounties::award_child_bounty_split(
+			RuntimeOrigin::signed(8),
+			0,
+			0,
+			vec![
+				(7, Perbill::from_percent(20)),
+				(9, Perbill::from_percent(40)),
+				(10, Perbill::from_percent(40))
+			]
+		));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::Awarded { index: 0, child_index: 0, beneficiary: 7, unlock_at: 5 }
+		);
+
+		System::set_block_number(5);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+
+		// The payout of 8 is split 1.6 / 3.2 / 3.2, the first beneficiary getting the remainder.
+		assert_eq!(Balances::free_balance(7), 2);
+		assert_eq!(Balances::free_balance(9), 3);
+		assert_eq!(Balances::free_balance(10), 3);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 0);
+		let claimed = System::events()
+			.into_iter()
+			.filter_map(|r| match r.event {
+				RuntimeEvent::ChildBounties(ChildBountiesEvent::Claimed {
+					payout,
+					beneficiary,
+					..
+				}) => Some((beneficiary, payout)),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(claimed, vec![(7, 2), (9, 3), (10, 3)]);
+		assert_eq!(ChildBounties::child_bounty_award_splits(0, 0), None);
+	});
+}
+
+#[test]
+fn unassign_curator_voids_award_split() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101);
+		Balances::make_free_balance_be(&8, 101);
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::award_child_bounty_split(
+			RuntimeOrigin::signed(8),
+			0,
+			0,
+			vec![(7, Perbill::from_percent(50)), (9, Perbill::from_percent(50))]
+		));
+		assert!(ChildBounties::child_bounty_award_splits(0, 0).is_some());
+
+		assert_ok!(ChildBounties::unassign_curator(RuntimeOrigin::signed(4), 0, 0));
+		assert_eq!(ChildBounties::child_bounty_award_splits(0, 0), None);
+	});
+}
+
+#[test]
+fn child_curator_heartbeat_resets_inactivity_timer() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101);
+		Balances::make_free_balance_be(&8, 101);
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		// The parent bounty update is due at block 12.
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+
+		// Only the curator of an active child-bounty can signal activity.
+		assert_noop!(
+			ChildBounties::child_curator_heartbeat(RuntimeOrigin::signed(8), 0, 0),
+			BountiesError::UnexpectedStatus
+		);
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_noop!(
+			ChildBounties::child_curator_heartbeat(RuntimeOrigin::signed(4), 0, 0),
+			BountiesError::RequireCurator
+		);
+
+		System::set_block_number(11);
+		assert_ok!(ChildBounties::child_curator_heartbeat(RuntimeOrigin::signed(8), 0, 0));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::ChildCuratorActive { index: 0, child_index: 0, update_due: 21 }
+		);
+		assert_eq!(ChildBounties::child_curator_update_due(0, 0), Some(21));
+
+		// The parent bounty update is overdue, but the child-bounty curator is active.
+		System::set_block_number(13);
+		assert_noop!(
+			ChildBounties::unassign_curator(RuntimeOrigin::signed(3), 0, 0),
+			BountiesError::Premature
+		);
+
+		// Once inactive again, anyone can unassign the child-bounty curator.
+		System::set_block_number(22);
+		assert_ok!(ChildBounties::unassign_curator(RuntimeOrigin::signed(3), 0, 0));
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap().status, ChildBountyStatus::Added);
+		assert_eq!(ChildBounties::child_curator_update_due(0, 0), None);
+	});
+}
+
+#[test]
+fn force_close_and_slash_child_curator_works() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+
+		// Nothing to slash without a curator.
+		assert_noop!(
+			ChildBounties::force_close_and_slash_child_curator(RuntimeOrigin::root(), 0, 0),
+			BountiesError::UnexpectedStatus
+		);
+
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		let expected_child_deposit = CuratorDepositMin::get();
+		assert_eq!(Balances::reserved_balance(8), expected_child_deposit);
+
+		// The child-bounty curator awards the child-bounty to themselves.
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 8));
+
+		// Only the reject origin can force close.
+		assert_noop!(
+			ChildBounties::force_close_and_slash_child_curator(RuntimeOrigin::signed(4), 0, 0),
+			BadOrigin
+		);
+		assert_ok!(ChildBounties::force_close_and_slash_child_curator(RuntimeOrigin::root(), 0, 0));
+
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::ChildBounties(ChildBountiesEvent::ChildCuratorSlashed {
+				index: 0,
+				child_index: 0,
+				curator: 8,
+				amount: expected_child_deposit,
+			})));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::Canceled {
+				index: 0,
+				child_index: 0,
+				reason: CloseReason::CuratorSlashed
+			}
+		);
+
+		// The child-bounty curator deposit is slashed.
+		assert_eq!(Balances::free_balance(8), 101 - expected_child_deposit);
+		assert_eq!(Balances::reserved_balance(8), 0);
+
+		// The child-bounty funds are back with the parent bounty.
+		assert_eq!(ChildBounties::child_bounties(0, 0), None);
+		assert_eq!(ChildBounties::parent_child_bounties(0), 0);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 50);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 0);
+	});
+}
+
+#[test]
+fn contribute_to_child_bounty_increases_payout() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+		Balances::make_free_balance_be(&9, 101); // Contributor.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+
+		// Only active child bounties can be funded.
+		assert_noop!(
+			ChildBounties::contribute_to_child_bounty(RuntimeOrigin::signed(9), 0, 0, 5),
+			BountiesError::UnexpectedStatus
+		);
+
+		let fee = 2;
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, fee));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+
+		assert_noop!(
+			ChildBounties::contribute_to_child_bounty(RuntimeOrigin::signed(9), 0, 0, 0),
+			BountiesError::InvalidValue
+		);
+		assert_ok!(ChildBounties::contribute_to_child_bounty(RuntimeOrigin::signed(9), 0, 0, 5));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::ChildBountyFunded { index: 0, child_index: 0, who: 9, amount: 5 }
+		);
+		assert_eq!(Balances::free_balance(9), 96);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 15);
+		let child_bounty = ChildBounties::child_bounties(0, 0).unwrap();
+		assert_eq!(child_bounty.value, 15);
+		assert_eq!(child_bounty.fee, fee);
+
+		// No more contributions once awarded.
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
+		assert_noop!(
+			ChildBounties::contribute_to_child_bounty(RuntimeOrigin::signed(9), 0, 0, 5),
+			BountiesError::UnexpectedStatus
+		);
+
+		System::set_block_number(9);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+
+		// The beneficiary gets the contribution, the curator only its fee.
+		assert_eq!(Balances::free_balance(7), 15 - fee);
+		assert_eq!(Balances::free_balance(8), 101 + fee);
+	});
+}
+
+#[test]
+fn claim_split_child_bounty_pays_out_contributions() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty, and an active child-bounty worth 10.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_ok!(ChildBounties::add_child_bounty(RuntimeOrigin::signed(4), 0, 10, b"c".to_vec()));
+		let fee = 2;
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, fee));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+
+		// Several contributions raise the value to 31.
+		for (who, amount) in [(9, 5), (10, 7), (11, 9)] {
+			Balances::make_free_balance_be(&who, 101);
+			assert_ok!(ChildBounties::contribute_to_child_bounty(
+				RuntimeOrigin::signed(who),
+				0,
+				0,
+				amount
+			));
+		}
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap().value, 31);
+
+		assert_ok!(ChildBounties::award_child_bounty_split(
+			RuntimeOrigin::signed(8),
+			0,
+			0,
+			vec![(5, Perbill::from_percent(50)), (6, Perbill::from_percent(50))]
+		));
+		System::set_block_number(9);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+
+		// The whole augmented value is paid out, and nothing is left behind.
+		assert_eq!(Balances::free_balance(5) + Balances::free_balance(6), 31 - fee);
+		assert_eq!(Balances::free_balance(8), 101 + fee);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 0);
+	});
+}
+
+#[test]
+fn transfer_child_curator_works() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty, and a child-bounty curated by 8.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+		Balances::make_free_balance_be(&9, 101); // Successor.
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_ok!(ChildBounties::add_child_bounty(RuntimeOrigin::signed(4), 0, 10, b"c".to_vec()));
+		let fee = 6;
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, fee));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		let deposit = CuratorDepositMultiplier::get() * fee;
+		assert_eq!(Balances::reserved_balance(8), deposit);
+
+		// Only the child-bounty curator can hand the role over.
+		assert_noop!(
+			ChildBounties::transfer_child_curator(RuntimeOrigin::signed(4), 0, 0, 9),
+			BountiesError::RequireCurator
+		);
+		assert_ok!(ChildBounties::transfer_child_curator(RuntimeOrigin::signed(8), 0, 0, 9));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::ChildCuratorTransferred {
+				index: 0,
+				child_index: 0,
+				from: 8,
+				to: 9
+			}
+		);
+
+		// The deposit is returned without slashing, and the successor is proposed.
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(Balances::free_balance(8), 101);
+		let child_bounty = ChildBounties::child_bounties(0, 0).unwrap();
+		assert_eq!(child_bounty.status, ChildBountyStatus::CuratorProposed { curator: 9 });
+		assert_eq!(child_bounty.fee, fee);
+		assert_eq!(child_bounty.curator_deposit, 0);
+
+		// The successor takes over the deposit, and can award the child-bounty.
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(9), 0, 0));
+		assert_eq!(Balances::reserved_balance(9), deposit);
+		assert_noop!(
+			ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7),
+			BountiesError::RequireCurator
+		);
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(9), 0, 0, 7));
+		assert!(matches!(
+			ChildBounties::child_bounties(0, 0).unwrap().status,
+			ChildBountyStatus::PendingPayout { curator: 9, beneficiary: 7, .. }
+		));
+	});
+}
+
+#[test]
+fn child_bounty_deposit_scales_with_description() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 1000); // Parent-bounty curator.
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Descriptions from empty up to `MaximumReasonLength`.
+		for (child_bounty_id, length) in [0, 1, 300].into_iter().enumerate() {
+			let child_bounty_id = child_bounty_id as BountyIndex;
+			let free_before = Balances::free_balance(4);
+			let reserved_before = Balances::reserved_balance(4);
+
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				vec![0; length as usize]
+			));
+			let deposit = 80 + length; // BountyDepositBase + DataDepositPerByte * length
+			assert_eq!(Balances::free_balance(4), free_before - deposit);
+			assert_eq!(Balances::reserved_balance(4), reserved_before + deposit);
+			assert_eq!(ChildBounties::child_bounty_deposits(child_bounty_id), Some((4, deposit)));
+
+			// Closing before a curator accepted refunds the whole deposit.
+			assert_ok!(ChildBounties::propose_curator(
+				RuntimeOrigin::signed(4),
+				0,
+				child_bounty_id,
+				8,
+				2
+			));
+			assert_ok!(ChildBounties::close_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				child_bounty_id
+			));
+			assert_eq!(Balances::free_balance(4), free_before);
+			assert_eq!(Balances::reserved_balance(4), reserved_before);
+			assert_eq!(ChildBounties::child_bounty_deposits(child_bounty_id), None);
+		}
+	});
+}
+
+#[test]
+fn child_bounties_stats_works() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 1000); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+		Balances::make_free_balance_be(&9, 100); // Contributor.
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_eq!(ChildBounties::child_bounties_stats(), Default::default());
+
+		// Child-bounty 0 is awarded, 1 is closed, 2 is added and 3 is active.
+		for child_bounty_id in 0..4 {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				b"c".to_vec()
+			));
+			if child_bounty_id == 1 {
+				assert_ok!(ChildBounties::close_child_bounty(RuntimeOrigin::signed(4), 0, 1));
+			}
+		}
+		for (child_bounty_id, fee) in [(0, 2), (3, 3)] {
+			assert_ok!(ChildBounties::propose_curator(
+				RuntimeOrigin::signed(4),
+				0,
+				child_bounty_id,
+				8,
+				fee
+			));
+			assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, child_bounty_id));
+		}
+		assert_ok!(ChildBounties::contribute_to_child_bounty(RuntimeOrigin::signed(9), 0, 3, 5));
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
+		assert_eq!(
+			ChildBounties::child_bounties_stats(),
+			ChildBountiesStats {
+				added: 1,
+				curator_proposed: 0,
+				active: 1,
+				pending_payout: 1,
+				claimed: 0,
+				canceled: 1,
+				value_locked: 35,
+				fees_pending: 5,
+				value_claimed: 0,
+			}
+		);
+
+		// Claim child-bounty 0, and propose a curator for 2.
+		System::set_block_number(5);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 2, 8, 2));
+		assert_eq!(
+			ChildBounties::child_bounties_stats(),
+			ChildBountiesStats {
+				added: 0,
+				curator_proposed: 1,
+				active: 1,
+				pending_payout: 0,
+				claimed: 1,
+				canceled: 1,
+				value_locked: 25,
+				fees_pending: 3,
+				value_claimed: 10,
+			}
+		);
+	});
+}
+
+#[test]
+fn child_bounties_stats_migration_works() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 1000); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Child-bounty 0 is awarded, 1 is closed, 2 has a proposed curator and 3 is active.
+		for child_bounty_id in 0..4 {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				b"c".to_vec()
+			));
+			if child_bounty_id == 1 {
+				assert_ok!(ChildBounties::close_child_bounty(RuntimeOrigin::signed(4), 0, 1));
+			}
+		}
+		for (child_bounty_id, fee) in [(0, 2), (3, 3)] {
+			assert_ok!(ChildBounties::propose_curator(
+				RuntimeOrigin::signed(4),
+				0,
+				child_bounty_id,
+				8,
+				fee
+			));
+			assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, child_bounty_id));
+		}
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 2, 8, 1));
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
+
+		// Pretend the statistics and parents were introduced after the child bounties were added.
+		ChildBountyStats::<Test>::kill();
+		let _ = ChildBountyParents::<Test>::clear(u32::MAX, None);
+		let _ = ParentChildBountiesValue::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(0).put::<ChildBounties>();
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		// The open child bounties are accounted for, the closed one is lost.
+		assert_eq!(StorageVersion::get::<ChildBounties>(), 1);
+		let expected = ChildBountiesStats {
+			added: 0,
+			curator_proposed: 1,
+			active: 1,
+			pending_payout: 1,
+			claimed: 0,
+			canceled: 0,
+			value_locked: 30,
+			fees_pending: 5,
+			value_claimed: 0,
+		};
+		assert_eq!(ChildBounties::child_bounties_stats(), expected);
+		for child_bounty_id in [0, 2, 3] {
+			assert_eq!(ChildBounties::parent_of_child(child_bounty_id), Some(0));
+		}
+		assert_eq!(ChildBounties::parent_of_child(1), None);
+		assert_eq!(ChildBounties::parent_child_bounties_value(0), 30);
+
+		// Running it again does not touch the statistics.
+		ChildBountyStats::<Test>::mutate(|stats| stats.claimed = 1);
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(
+			ChildBounties::child_bounties_stats(),
+			ChildBountiesStats { claimed: 1, ..expected }
+		);
+	});
+}
+
+#[test]
+fn status_changed_events_follow_lifecycle() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 1000); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Walk a child-bounty through to its claim, and close another one.
+		for _ in 0..2 {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				b"c".to_vec()
+			));
+		}
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
+		System::set_block_number(5);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 1, 8, 2));
+		assert_ok!(ChildBounties::close_child_bounty(RuntimeOrigin::signed(4), 0, 1));
+
+		let transitions = System::events()
+			.into_iter()
+			.filter_map(|r| match r.event {
+				RuntimeEvent::ChildBounties(ChildBountiesEvent::ChildBountyStatusChanged {
+					parent_id,
+					child_id,
+					from,
+					to,
+				}) => Some((parent_id, child_id, from, to)),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		use ChildBountyState::*;
+		assert_eq!(
+			transitions,
+			vec![
+				(0, 0, Added, CuratorProposed),
+				(0, 0, CuratorProposed, Active),
+				(0, 0, Active, PendingPayout),
+				(0, 0, PendingPayout, Claimed),
+				(0, 1, Added, CuratorProposed),
+				(0, 1, CuratorProposed, Canceled),
+			]
+		);
+	});
+}
+
+#[test]
+fn claimable_child_bounties_works() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty, and two active child bounties.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 1000); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		for child_bounty_id in 0..2 {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				b"c".to_vec()
+			));
+			assert_ok!(ChildBounties::propose_curator(
+				RuntimeOrigin::signed(4),
+				0,
+				child_bounty_id,
+				8,
+				2
+			));
+			assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, child_bounty_id));
+		}
+
+		// Award them at different blocks, unlocking at blocks 5 and 7.
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
+		System::set_block_number(4);
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 1, 9));
+
+		assert_eq!(ChildBounties::claimable_child_bounties(4, 10), vec![]);
+		assert_eq!(ChildBounties::claimable_child_bounties(5, 10), vec![(0, 0, 7)]);
+		assert_eq!(ChildBounties::claimable_child_bounties(7, 10), vec![(0, 0, 7), (0, 1, 9)]);
+		assert_eq!(ChildBounties::claimable_child_bounties(7, 1), vec![(0, 0, 7)]);
+
+		// Once claimed, a child-bounty is no longer listed.
+		System::set_block_number(7);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+		assert_eq!(ChildBounties::claimable_child_bounties(7, 1), vec![(0, 1, 9)]);
+	});
+}
+
+#[test]
+fn parent_of_child_works() {
+	new_test_ext().execute_with(|| {
+		// Make two parent bounties, and a child-bounty of the second one.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 1000);
+		Balances::make_free_balance_be(&4, 1000);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 30, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 1));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 1, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 1));
+
+		assert_eq!(ChildBounties::parent_of_child(0), None);
+		assert_ok!(ChildBounties::add_child_bounty(RuntimeOrigin::signed(4), 1, 10, b"c".to_vec()));
+		assert_eq!(ChildBounties::parent_of_child(0), Some(1));
+
+		// The lookup is cleaned up once the child-bounty is claimed.
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 1, 0, 8, 2));
+		Balances::make_free_balance_be(&8, 101);
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 1, 0));
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 1, 0, 7));
+		System::set_block_number(9);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 1, 0));
+		assert_eq!(ChildBounties::parent_of_child(0), None);
+	});
+}
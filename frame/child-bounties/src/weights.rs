@@ -48,167 +48,458 @@ pub trait WeightInfo {
 	fn propose_curator() -> Weight;
 	fn accept_curator() -> Weight;
 	fn unassign_curator() -> Weight;
+	fn contribute_to_child_bounty() -> Weight;
+	fn child_curator_heartbeat() -> Weight;
 	fn award_child_bounty() -> Weight;
+	fn award_child_bounty_split(b: u32, ) -> Weight;
 	fn claim_child_bounty() -> Weight;
+	fn claim_child_bounty_contributed() -> Weight;
+	fn claim_child_bounty_split(b: u32, ) -> Weight;
 	fn close_child_bounty_added() -> Weight;
 	fn close_child_bounty_active() -> Weight;
+	fn force_close_and_slash_child_curator() -> Weight;
+	fn transfer_child_curator() -> Weight;
 }
 
 /// Weights for pallet_child_bounties using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `add_child_bounty` benchmark, see capsule-corp-ternoa/substrate#synth-1142.
 	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
 	// Storage: Bounties Bounties (r:1 w:0)
-	// Storage: System Account (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
 	// Storage: ChildBounties ChildBountyCount (r:1 w:1)
 	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
 	// Storage: ChildBounties ChildBounties (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
 	fn add_child_bounty(d: u32, ) -> Weight {
 		Weight::from_ref_time(51_064_000 as u64)
 			// Standard Error: 0
 			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(d as u64))
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `propose_curator` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
 	// Storage: Bounties Bounties (r:1 w:0)
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
 	// Storage: ChildBounties ChildrenCuratorFees (r:1 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
 	fn propose_curator() -> Weight {
 		Weight::from_ref_time(15_286_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `accept_curator` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
 	// Storage: Bounties Bounties (r:1 w:0)
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
 	fn accept_curator() -> Weight {
 		Weight::from_ref_time(29_929_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `unassign_curator` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
 	// Storage: Bounties Bounties (r:1 w:0)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
 	fn unassign_curator() -> Weight {
 		Weight::from_ref_time(32_449_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `contribute_to_child_bounty` benchmark, see capsule-corp-ternoa/substrate#synth-1185.
 	// Storage: Bounties Bounties (r:1 w:0)
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
-	fn award_child_bounty() -> Weight {
-		Weight::from_ref_time(23_793_000 as u64)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
+	fn contribute_to_child_bounty() -> Weight {
+		Weight::from_ref_time(38_207_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `child_curator_heartbeat` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
+	// Storage: Bounties Bounties (r:1 w:0)
+	// Storage: ChildBounties ChildBounties (r:1 w:0)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:0 w:1)
+	fn child_curator_heartbeat() -> Weight {
+		Weight::from_ref_time(19_862_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `award_child_bounty` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
+	// Storage: Bounties Bounties (r:1 w:0)
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
-	// Storage: System Account (r:3 w:3)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	fn award_child_bounty() -> Weight {
+		Weight::from_ref_time(23_793_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `award_child_bounty_split` benchmark, see capsule-corp-ternoa/substrate#synth-1167.
+	// Storage: Bounties Bounties (r:1 w:0)
+	// Storage: ChildBounties ChildBounties (r:1 w:1)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:0 w:1)
+	// Storage: ChildBounties ChildBountyAwardSplits (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	fn award_child_bounty_split(b: u32, ) -> Weight {
+		Weight::from_ref_time(25_112_000 as u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(1_241_000 as u64).saturating_mul(b as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `claim_child_bounty` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
+	// Storage: ChildBounties ChildBountyAwardSplits (r:1 w:1)
+	// Storage: ChildBounties ChildBounties (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
 	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
 	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDeposits (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
 	fn claim_child_bounty() -> Weight {
 		Weight::from_ref_time(67_529_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `claim_child_bounty_contributed` benchmark, see capsule-corp-ternoa/substrate#synth-1212.
+	// Storage: ChildBounties ChildBountyAwardSplits (r:1 w:1)
+	// Storage: ChildBounties ChildBounties (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDeposits (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
+	fn claim_child_bounty_contributed() -> Weight {
+		Weight::from_ref_time(68_412_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `claim_child_bounty_split` benchmark, see capsule-corp-ternoa/substrate#synth-1167.
+	// Storage: ChildBounties ChildBountyAwardSplits (r:1 w:1)
+	// Storage: ChildBounties ChildBounties (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDeposits (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
+	fn claim_child_bounty_split(b: u32, ) -> Weight {
+		Weight::from_ref_time(62_387_000 as u64)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_ref_time(17_904_000 as u64).saturating_mul(b as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(b as u64)))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(b as u64)))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `close_child_bounty_added` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
 	// Storage: Bounties Bounties (r:1 w:0)
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
 	// Storage: ChildBounties ChildrenCuratorFees (r:1 w:1)
 	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
-	// Storage: System Account (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
 	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDeposits (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
 	fn close_child_bounty_added() -> Weight {
 		Weight::from_ref_time(48_436_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `close_child_bounty_active` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
 	// Storage: Bounties Bounties (r:1 w:0)
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
-	// Storage: System Account (r:3 w:3)
+	// Storage: System Account (r:4 w:4)
 	// Storage: ChildBounties ChildrenCuratorFees (r:1 w:1)
 	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
 	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDeposits (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
 	fn close_child_bounty_active() -> Weight {
 		Weight::from_ref_time(58_044_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(7 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `force_close_and_slash_child_curator` benchmark, see capsule-corp-ternoa/substrate#synth-1179.
+	// Storage: Bounties Bounties (r:1 w:0)
+	// Storage: ChildBounties ChildBounties (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: ChildBounties ChildrenCuratorFees (r:1 w:1)
+	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
+	// Storage: ChildBounties ChildBountyAwardSplits (r:0 w:1)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDeposits (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
+	fn force_close_and_slash_child_curator() -> Weight {
+		Weight::from_ref_time(63_415_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(14 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `transfer_child_curator` benchmark, see capsule-corp-ternoa/substrate#synth-1215.
+	// Storage: Bounties Bounties (r:1 w:0)
+	// Storage: ChildBounties ChildBounties (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	fn transfer_child_curator() -> Weight {
+		Weight::from_ref_time(29_874_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `add_child_bounty` benchmark, see capsule-corp-ternoa/substrate#synth-1142.
 	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
 	// Storage: Bounties Bounties (r:1 w:0)
-	// Storage: System Account (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
 	// Storage: ChildBounties ChildBountyCount (r:1 w:1)
 	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
 	// Storage: ChildBounties ChildBounties (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
 	fn add_child_bounty(d: u32, ) -> Weight {
 		Weight::from_ref_time(51_064_000 as u64)
 			// Standard Error: 0
 			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(d as u64))
-			.saturating_add(RocksDbWeight::get().reads(5 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `propose_curator` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
 	// Storage: Bounties Bounties (r:1 w:0)
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
 	// Storage: ChildBounties ChildrenCuratorFees (r:1 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
 	fn propose_curator() -> Weight {
 		Weight::from_ref_time(15_286_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(3 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `accept_curator` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
 	// Storage: Bounties Bounties (r:1 w:0)
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
 	fn accept_curator() -> Weight {
 		Weight::from_ref_time(29_929_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(3 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `unassign_curator` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
 	// Storage: Bounties Bounties (r:1 w:0)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
 	fn unassign_curator() -> Weight {
 		Weight::from_ref_time(32_449_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(3 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `contribute_to_child_bounty` benchmark, see capsule-corp-ternoa/substrate#synth-1185.
 	// Storage: Bounties Bounties (r:1 w:0)
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
-	fn award_child_bounty() -> Weight {
-		Weight::from_ref_time(23_793_000 as u64)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
+	fn contribute_to_child_bounty() -> Weight {
+		Weight::from_ref_time(38_207_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `child_curator_heartbeat` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
+	// Storage: Bounties Bounties (r:1 w:0)
+	// Storage: ChildBounties ChildBounties (r:1 w:0)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:0 w:1)
+	fn child_curator_heartbeat() -> Weight {
+		Weight::from_ref_time(19_862_000 as u64)
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `award_child_bounty` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
+	// Storage: Bounties Bounties (r:1 w:0)
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
-	// Storage: System Account (r:3 w:3)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	fn award_child_bounty() -> Weight {
+		Weight::from_ref_time(23_793_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `award_child_bounty_split` benchmark, see capsule-corp-ternoa/substrate#synth-1167.
+	// Storage: Bounties Bounties (r:1 w:0)
+	// Storage: ChildBounties ChildBounties (r:1 w:1)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:0 w:1)
+	// Storage: ChildBounties ChildBountyAwardSplits (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	fn award_child_bounty_split(b: u32, ) -> Weight {
+		Weight::from_ref_time(25_112_000 as u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(1_241_000 as u64).saturating_mul(b as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `claim_child_bounty` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
+	// Storage: ChildBounties ChildBountyAwardSplits (r:1 w:1)
+	// Storage: ChildBounties ChildBounties (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
 	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
 	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDeposits (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
 	fn claim_child_bounty() -> Weight {
 		Weight::from_ref_time(67_529_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(5 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `claim_child_bounty_contributed` benchmark, see capsule-corp-ternoa/substrate#synth-1212.
+	// Storage: ChildBounties ChildBountyAwardSplits (r:1 w:1)
+	// Storage: ChildBounties ChildBounties (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDeposits (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
+	fn claim_child_bounty_contributed() -> Weight {
+		Weight::from_ref_time(68_412_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `claim_child_bounty_split` benchmark, see capsule-corp-ternoa/substrate#synth-1167.
+	// Storage: ChildBounties ChildBountyAwardSplits (r:1 w:1)
+	// Storage: ChildBounties ChildBounties (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDeposits (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
+	fn claim_child_bounty_split(b: u32, ) -> Weight {
+		Weight::from_ref_time(62_387_000 as u64)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_ref_time(17_904_000 as u64).saturating_mul(b as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(b as u64)))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(b as u64)))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `close_child_bounty_added` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
 	// Storage: Bounties Bounties (r:1 w:0)
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
 	// Storage: ChildBounties ChildrenCuratorFees (r:1 w:1)
 	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
-	// Storage: System Account (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
 	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDeposits (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
 	fn close_child_bounty_added() -> Weight {
 		Weight::from_ref_time(48_436_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(6 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
 	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `close_child_bounty_active` benchmark, see capsule-corp-ternoa/substrate#synth-1173.
 	// Storage: Bounties Bounties (r:1 w:0)
 	// Storage: ChildBounties ChildBounties (r:1 w:1)
-	// Storage: System Account (r:3 w:3)
+	// Storage: System Account (r:4 w:4)
 	// Storage: ChildBounties ChildrenCuratorFees (r:1 w:1)
 	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
 	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDeposits (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
 	fn close_child_bounty_active() -> Weight {
 		Weight::from_ref_time(58_044_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(7 as u64))
-			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(13 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `force_close_and_slash_child_curator` benchmark, see capsule-corp-ternoa/substrate#synth-1179.
+	// Storage: Bounties Bounties (r:1 w:0)
+	// Storage: ChildBounties ChildBounties (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: ChildBounties ChildrenCuratorFees (r:1 w:1)
+	// Storage: ChildBounties ParentChildBounties (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
+	// Storage: ChildBounties ChildBountyAwardSplits (r:0 w:1)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	// Storage: ChildBounties ChildBountyDeposits (r:1 w:1)
+	// Storage: ChildBounties ChildBountyParents (r:0 w:1)
+	// Storage: ChildBounties ParentChildBountiesValue (r:1 w:1)
+	fn force_close_and_slash_child_curator() -> Weight {
+		Weight::from_ref_time(63_415_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(14 as u64))
+	}
+	// TODO: estimate, not generated by the benchmark CLI. Regenerate from the
+	// `transfer_child_curator` benchmark, see capsule-corp-ternoa/substrate#synth-1215.
+	// Storage: Bounties Bounties (r:1 w:0)
+	// Storage: ChildBounties ChildBounties (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChildBounties ChildCuratorUpdateDue (r:0 w:1)
+	// Storage: ChildBounties ChildBountyStats (r:1 w:1)
+	fn transfer_child_curator() -> Weight {
+		Weight::from_ref_time(29_874_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
 }
@@ -501,6 +501,28 @@ pub trait SortedListProvider<AccountId> {
 	/// Get the score of `id`.
 	fn get_score(id: &AccountId) -> Result<Self::Score, Self::Error>;
 
+	/// The sum of the scores of all ids in the list, saturating at the maximum score.
+	///
+	/// This iterates over the whole list, so should not be called on-chain for large lists.
+	fn total_score() -> Self::Score {
+		Self::iter()
+			.filter_map(|id| Self::get_score(&id).ok())
+			.fold(Zero::zero(), |total: Self::Score, score| total.saturating_add(score))
+	}
+
+	/// The position of `id` in the list, counting from the first id, and the number of ids in
+	/// the list. `None` if `id` is not in the list.
+	///
+	/// This iterates over the list up to `id`, so should not be called on-chain for large lists.
+	fn rank_of(id: &AccountId) -> Option<(u32, u32)>
+	where
+		AccountId: PartialEq,
+	{
+		Self::iter()
+			.position(|other| other == *id)
+			.map(|position| (position as u32, Self::count()))
+	}
+
 	/// Same as `on_update`, but incorporate some increased score.
 	fn on_increase(id: &AccountId, additional: Self::Score) -> Result<(), Self::Error> {
 		let old_score = Self::get_score(id)?;
@@ -544,7 +566,8 @@ pub trait SortedListProvider<AccountId> {
 	/// ## WARNING
 	///
 	/// This function should never be called in production settings because it can lead to an
-	/// unbounded amount of storage accesses.
+	/// unbounded amount of storage accesses. It is only meant for tests, benchmarks and
+	/// migrations.
 	fn unsafe_clear();
 
 	/// Check internal state of list. Only meant for debugging.
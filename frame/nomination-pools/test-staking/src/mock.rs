@@ -144,6 +144,10 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type BagThresholds = BagThresholds;
+	type MaxReasonableWeight = ();
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type OnRemoveGuard = ();
+	type NodeMetadata = ();
 	type ScoreProvider = Staking;
 	type Score = VoteWeight;
 }
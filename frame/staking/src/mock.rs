@@ -247,6 +247,10 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Test {
 	// Staking is the source of truth for voter bags list, since they are not kept up to date.
 	type ScoreProvider = Staking;
 	type BagThresholds = BagThresholds;
+	type MaxReasonableWeight = ();
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type OnRemoveGuard = ();
+	type NodeMetadata = ();
 	type Score = VoteWeight;
 }
 
@@ -86,6 +86,10 @@ impl<T: Config + Send + Sync> SignedExtension for CheckMortality<T> {
 		}
 	}
 
+	fn era(&self) -> Option<Era> {
+		Some(self.0)
+	}
+
 	fn pre_dispatch(
 		self,
 		who: &Self::AccountId,
@@ -49,4 +49,17 @@ sp_api::decl_runtime_apis! {
 		/// Check that the inherents are valid. The inherent data will vary from chain to chain.
 		fn check_inherents(block: Block, data: InherentData) -> CheckInherentsResult;
 	}
+
+	/// The `DryRunApi` api trait that reports what applying an extrinsic would do.
+	pub trait DryRunApi {
+		/// Apply the given extrinsic, like [`BlockBuilder::apply_extrinsic`], in a child of the
+		/// block with the given `block_hash`, whose state the call is executed on.
+		///
+		/// Returns the inclusion outcome along with the SCALE encoded `Vec` of the event records
+		/// deposited while applying the extrinsic. The caller is expected to discard the changes.
+		fn dry_run_extrinsic(
+			extrinsic: <Block as BlockT>::Extrinsic,
+			block_hash: <Block as BlockT>::Hash,
+		) -> (ApplyExtrinsicResult, sp_std::vec::Vec<u8>);
+	}
 }
@@ -265,6 +265,13 @@ pub trait SyncCryptoStore: CryptoStore + Send + Sync {
 	/// `Err` if there's some sort of weird filesystem error, but should generally be `Ok`.
 	fn insert_unknown(&self, key_type: KeyTypeId, suri: &str, public: &[u8]) -> Result<(), ()>;
 
+	/// Returns whether new keys could currently be inserted, without inserting anything.
+	///
+	/// Defaults to `true`.
+	fn is_writable(&self) -> bool {
+		true
+	}
+
 	/// Find intersection between provided keys and supported keys
 	///
 	/// Provided a list of (CryptoTypeId,[u8]) pairs, this would return
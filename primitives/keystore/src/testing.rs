@@ -38,6 +38,8 @@ use std::{
 pub struct KeyStore {
 	/// `KeyTypeId` maps to public keys and public keys map to private keys.
 	keys: Arc<RwLock<HashMap<KeyTypeId, HashMap<Vec<u8>, String>>>>,
+	/// Whether inserting keys is refused.
+	read_only: bool,
 }
 
 impl KeyStore {
@@ -46,6 +48,11 @@ impl KeyStore {
 		Self::default()
 	}
 
+	/// Creates a new instance of `Self` that refuses to insert keys.
+	pub fn new_read_only() -> Self {
+		Self { read_only: true, ..Default::default() }
+	}
+
 	fn sr25519_key_pair(&self, id: KeyTypeId, pub_key: &sr25519::Public) -> Option<sr25519::Pair> {
 		self.keys.read().get(&id).and_then(|inner| {
 			inner.get(pub_key.as_slice()).map(|s| {
@@ -305,6 +312,9 @@ impl SyncCryptoStore for KeyStore {
 	}
 
 	fn insert_unknown(&self, id: KeyTypeId, suri: &str, public: &[u8]) -> Result<(), ()> {
+		if self.read_only {
+			return Err(())
+		}
 		self.keys
 			.write()
 			.entry(id)
@@ -313,6 +323,10 @@ impl SyncCryptoStore for KeyStore {
 		Ok(())
 	}
 
+	fn is_writable(&self) -> bool {
+		!self.read_only
+	}
+
 	fn has_keys(&self, public_keys: &[(Vec<u8>, KeyTypeId)]) -> bool {
 		public_keys
 			.iter()
@@ -18,7 +18,7 @@
 //! Generic implementation of an unchecked (pre-verification) extrinsic.
 
 use crate::{
-	generic::CheckedExtrinsic,
+	generic::{CheckedExtrinsic, Era},
 	traits::{
 		self, Checkable, Extrinsic, ExtrinsicMetadata, IdentifyAccount, MaybeDisplay, Member,
 		SignedExtension,
@@ -113,7 +113,7 @@ impl<Address, Call, Signature, Extra: SignedExtension>
 	}
 }
 
-impl<Address, Call, Signature, Extra: SignedExtension> Extrinsic
+impl<Address, Call: Encode, Signature, Extra: SignedExtension> Extrinsic
 	for UncheckedExtrinsic<Address, Call, Signature, Extra>
 {
 	type Call = Call;
@@ -124,6 +124,18 @@ impl<Address, Call, Signature, Extra: SignedExtension> Extrinsic
 		Some(self.signature.is_some())
 	}
 
+	fn era(&self) -> Option<Era> {
+		self.signature.as_ref().and_then(|(_, _, extra)| extra.era())
+	}
+
+	fn encoded_call(&self) -> Option<Vec<u8>> {
+		Some(self.function.encode())
+	}
+
+	fn signed_extensions(&self) -> Option<Vec<(&'static str, Vec<u8>)>> {
+		self.signature.as_ref().map(|(_, _, extra)| extra.encoded_parts())
+	}
+
 	fn new(function: Call, signed_data: Option<Self::SignaturePayload>) -> Option<Self> {
 		Some(if let Some((address, signature, extra)) = signed_data {
 			Self::new_signed(function, address, signature, extra)
@@ -461,6 +473,115 @@ mod tests {
 		assert_eq!(Ex::decode(&mut &encoded[..]), Ok(ux));
 	}
 
+	#[test]
+	fn era_is_taken_from_signed_extensions() {
+		#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+		struct TestEra(Era);
+		impl SignedExtension for TestEra {
+			const IDENTIFIER: &'static str = "TestEra";
+			type AccountId = u64;
+			type Call = ();
+			type AdditionalSigned = ();
+			type Pre = ();
+
+			fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+				Ok(())
+			}
+
+			fn pre_dispatch(
+				self,
+				who: &Self::AccountId,
+				call: &Self::Call,
+				info: &DispatchInfoOf<Self::Call>,
+				len: usize,
+			) -> Result<Self::Pre, TransactionValidityError> {
+				self.validate(who, call, info, len).map(|_| ())
+			}
+
+			fn era(&self) -> Option<Era> {
+				Some(self.0)
+			}
+		}
+
+		type EraEx = UncheckedExtrinsic<TestAccountId, TestCall, TestSig, (TestExtra, TestEra)>;
+
+		let era = Era::mortal(64, 42);
+		let extra = (TestExtra, TestEra(era));
+		let ux = EraEx::new_signed(
+			vec![0u8; 0],
+			TEST_ACCOUNT,
+			TestSig(TEST_ACCOUNT, (vec![0u8; 0], extra.clone()).encode()),
+			extra,
+		);
+		assert_eq!(Extrinsic::era(&ux), Some(era));
+
+		// Unsigned extrinsics have no era.
+		assert_eq!(Extrinsic::era(&EraEx::new_unsigned(vec![0u8; 0])), None);
+		// Neither do those without an extension checking one.
+		let ux = Ex::new_signed(
+			vec![0u8; 0],
+			TEST_ACCOUNT,
+			TestSig(TEST_ACCOUNT, (vec![0u8; 0], TestExtra).encode()),
+			TestExtra,
+		);
+		assert_eq!(Extrinsic::era(&ux), None);
+	}
+
+	#[test]
+	fn encoded_call_strips_signature() {
+		let call = vec![1u8, 2, 3];
+		let ux = Ex::new_signed(
+			call.clone(),
+			TEST_ACCOUNT,
+			TestSig(TEST_ACCOUNT, (call.clone(), TestExtra).encode()),
+			TestExtra,
+		);
+		assert_eq!(ux.encoded_call(), Some(call.encode()));
+		assert_eq!(Ex::new_unsigned(call.clone()).encoded_call(), Some(call.encode()));
+	}
+
+	#[test]
+	fn signed_extensions_are_listed_in_order() {
+		#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+		struct TestNonce(u32);
+		impl SignedExtension for TestNonce {
+			const IDENTIFIER: &'static str = "TestNonce";
+			type AccountId = u64;
+			type Call = ();
+			type AdditionalSigned = ();
+			type Pre = ();
+
+			fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+				Ok(())
+			}
+
+			fn pre_dispatch(
+				self,
+				who: &Self::AccountId,
+				call: &Self::Call,
+				info: &DispatchInfoOf<Self::Call>,
+				len: usize,
+			) -> Result<Self::Pre, TransactionValidityError> {
+				self.validate(who, call, info, len).map(|_| ())
+			}
+		}
+
+		type NonceEx = UncheckedExtrinsic<TestAccountId, TestCall, TestSig, (TestNonce, TestExtra)>;
+
+		let extra = (TestNonce(7), TestExtra);
+		let ux = NonceEx::new_signed(
+			vec![0u8; 0],
+			TEST_ACCOUNT,
+			TestSig(TEST_ACCOUNT, (vec![0u8; 0], extra.clone()).encode()),
+			extra,
+		);
+		assert_eq!(
+			ux.signed_extensions(),
+			Some(vec![("TestNonce", 7u32.encode()), ("TestExtra", vec![])])
+		);
+		assert_eq!(NonceEx::new_unsigned(vec![0u8; 0]).signed_extensions(), None);
+	}
+
 	#[test]
 	fn unsigned_check_should_work() {
 		let ux = Ex::new_unsigned(vec![0u8; 0]);
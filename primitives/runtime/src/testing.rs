@@ -342,6 +342,10 @@ impl<Call: Codec + Sync + Send, Extra> traits::Extrinsic for TestXt<Call, Extra>
 		Some(self.signature.is_some())
 	}
 
+	fn encoded_call(&self) -> Option<Vec<u8>> {
+		Some(self.call.encode())
+	}
+
 	fn new(c: Call, sig: Option<Self::SignaturePayload>) -> Option<Self> {
 		Some(TestXt { signature: sig, call: c })
 	}
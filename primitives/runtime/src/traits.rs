@@ -19,7 +19,7 @@
 
 use crate::{
 	codec::{Codec, Decode, Encode, MaxEncodedLen},
-	generic::Digest,
+	generic::{Digest, Era},
 	scale_info::{MetaType, StaticTypeInfo, TypeInfo},
 	transaction_validity::{
 		TransactionSource, TransactionValidity, TransactionValidityError, UnknownTransaction,
@@ -951,6 +951,26 @@ pub trait Extrinsic: Sized + MaybeMallocSizeOf {
 		None
 	}
 
+	/// The mortality era of this `Extrinsic`.
+	/// `None` if it is unsigned or none of its signed extensions checks an era.
+	fn era(&self) -> Option<Era> {
+		None
+	}
+
+	/// The SCALE-encoded call of this `Extrinsic`, stripped of any signature and signed
+	/// extension data.
+	/// `None` if no information is available about the call.
+	fn encoded_call(&self) -> Option<Vec<u8>> {
+		None
+	}
+
+	/// The identifier and SCALE-encoded explicit data of each signed extension of this
+	/// `Extrinsic`, see [`SignedExtension::encoded_parts`].
+	/// `None` if it is unsigned or no information is available about its signed extensions.
+	fn signed_extensions(&self) -> Option<Vec<(&'static str, Vec<u8>)>> {
+		None
+	}
+
 	/// Create new instance of the extrinsic.
 	///
 	/// Extrinsics can be split into:
@@ -1182,6 +1202,21 @@ pub trait SignedExtension:
 			additional_signed: scale_info::meta_type::<Self::AdditionalSigned>()
 		}]
 	}
+
+	/// Returns the mortality era this signed extension checks, if any.
+	///
+	/// Only the extension checking the mortality of a transaction is expected to return `Some`.
+	fn era(&self) -> Option<Era> {
+		None
+	}
+
+	/// Returns the identifier and SCALE-encoded explicit data of this signed extension.
+	///
+	/// As with [`Self::metadata`], a tuple returns one entry for each of its signed extensions,
+	/// in order. The additional signed data is not included since it may depend on state.
+	fn encoded_parts(&self) -> Vec<(&'static str, Vec<u8>)> {
+		sp_std::vec![(Self::IDENTIFIER, self.encode())]
+	}
 }
 
 /// Information about a [`SignedExtension`] for the runtime metadata.
@@ -1271,6 +1306,17 @@ impl<AccountId, Call: Dispatchable> SignedExtension for Tuple {
 		for_tuples!( #( ids.extend(Tuple::metadata()); )* );
 		ids
 	}
+
+	fn era(&self) -> Option<Era> {
+		for_tuples!( #( if let Some(era) = Tuple.era() { return Some(era) } )* );
+		None
+	}
+
+	fn encoded_parts(&self) -> Vec<(&'static str, Vec<u8>)> {
+		let mut parts = Vec::new();
+		for_tuples!( #( parts.extend(Tuple.encoded_parts()); )* );
+		parts
+	}
 }
 
 /// Only for bare bone testing when you don't care about signed extensions at all.
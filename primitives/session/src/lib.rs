@@ -47,6 +47,14 @@ sp_api::decl_runtime_apis! {
 		/// Returns the list of public raw public keys + key type.
 		fn decode_session_keys(encoded: Vec<u8>) -> Option<Vec<(Vec<u8>, KeyTypeId)>>;
 	}
+
+	/// Runtime api exposing the session keys of the validators.
+	pub trait ValidatorSessionKeys {
+		/// The session keys of each of the current validators.
+		///
+		/// Returns the list of raw public keys + key type of each validator.
+		fn validator_session_keys() -> Vec<Vec<(Vec<u8>, KeyTypeId)>>;
+	}
 }
 
 /// Number of validators in a given session.
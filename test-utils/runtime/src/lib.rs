@@ -228,6 +228,15 @@ impl ExtrinsicT for Extrinsic {
 		}
 	}
 
+	fn encoded_call(&self) -> Option<Vec<u8>> {
+		Some(self.encode())
+	}
+
+	fn signed_extensions(&self) -> Option<Vec<(&'static str, Vec<u8>)>> {
+		// Signed extrinsics of the test runtime don't carry any signed extensions.
+		self.is_signed().unwrap_or(false).then(Vec::new)
+	}
+
 	fn new(call: Self::Call, _signature_payload: Option<Self::SignaturePayload>) -> Option<Self> {
 		Some(call)
 	}
@@ -543,6 +552,9 @@ impl frame_support::traits::PalletInfo for Runtime {
 		if type_id == sp_std::any::TypeId::of::<pallet_babe::Pallet<Runtime>>() {
 			return Some(2)
 		}
+		if type_id == sp_std::any::TypeId::of::<frame_system::Pallet<Runtime>>() {
+			return Some(3)
+		}
 
 		None
 	}
@@ -557,6 +569,10 @@ impl frame_support::traits::PalletInfo for Runtime {
 		if type_id == sp_std::any::TypeId::of::<pallet_babe::Pallet<Runtime>>() {
 			return Some("Babe")
 		}
+		// Only used by dry runs, named so as not to share the storage of `system`.
+		if type_id == sp_std::any::TypeId::of::<frame_system::Pallet<Runtime>>() {
+			return Some("FrameSystem")
+		}
 
 		None
 	}
@@ -571,6 +587,9 @@ impl frame_support::traits::PalletInfo for Runtime {
 		if type_id == sp_std::any::TypeId::of::<pallet_babe::Pallet<Runtime>>() {
 			return Some("pallet_babe")
 		}
+		if type_id == sp_std::any::TypeId::of::<frame_system::Pallet<Runtime>>() {
+			return Some("frame_system")
+		}
 
 		None
 	}
@@ -586,6 +605,9 @@ impl frame_support::traits::PalletInfo for Runtime {
 		if type_id == sp_std::any::TypeId::of::<pallet_babe::Pallet<Runtime>>() {
 			return Some(pallet_babe::Pallet::<Runtime>::crate_version())
 		}
+		if type_id == sp_std::any::TypeId::of::<frame_system::Pallet<Runtime>>() {
+			return Some(frame_system::Pallet::<Runtime>::crate_version())
+		}
 
 		None
 	}
@@ -778,6 +800,17 @@ cfg_if! {
 				}
 			}
 
+			impl sp_block_builder::DryRunApi<Block> for Runtime {
+				fn dry_run_extrinsic(
+					extrinsic: <Block as BlockT>::Extrinsic,
+					block_hash: <Block as BlockT>::Hash,
+				) -> (ApplyExtrinsicResult, Vec<u8>) {
+					system::initialize_dry_run(block_hash);
+					let result = system::execute_transaction(extrinsic);
+					(result, <frame_system::Pallet<Runtime>>::read_events_no_consensus().encode())
+				}
+			}
+
 			impl self::TestAPI<Block> for Runtime {
 				fn balance_of(id: AccountId) -> u64 {
 					system::balance_of(id)
@@ -939,6 +972,19 @@ cfg_if! {
 				}
 			}
 
+			impl sp_session::ValidatorSessionKeys<Block> for Runtime {
+				fn validator_session_keys() -> Vec<Vec<(Vec<u8>, sp_core::crypto::KeyTypeId)>> {
+					// The authorities stand in for the validators, with their BABE key only.
+					system::authorities()
+						.iter()
+						.map(|authority| {
+							let public = sp_core::crypto::ByteArray::to_raw_vec(authority);
+							vec![(public, sp_consensus_babe::KEY_TYPE)]
+						})
+						.collect()
+				}
+			}
+
 			impl sp_finality_grandpa::GrandpaApi<Block> for Runtime {
 				fn grandpa_authorities() -> sp_finality_grandpa::AuthorityList {
 					Vec::new()
@@ -1048,6 +1094,17 @@ cfg_if! {
 				}
 			}
 
+			impl sp_block_builder::DryRunApi<Block> for Runtime {
+				fn dry_run_extrinsic(
+					extrinsic: <Block as BlockT>::Extrinsic,
+					block_hash: <Block as BlockT>::Hash,
+				) -> (ApplyExtrinsicResult, Vec<u8>) {
+					system::initialize_dry_run(block_hash);
+					let result = system::execute_transaction(extrinsic);
+					(result, <frame_system::Pallet<Runtime>>::read_events_no_consensus().encode())
+				}
+			}
+
 			impl self::TestAPI<Block> for Runtime {
 				fn balance_of(id: AccountId) -> u64 {
 					system::balance_of(id)
@@ -1213,6 +1270,19 @@ cfg_if! {
 				}
 			}
 
+			impl sp_session::ValidatorSessionKeys<Block> for Runtime {
+				fn validator_session_keys() -> Vec<Vec<(Vec<u8>, sp_core::crypto::KeyTypeId)>> {
+					// The authorities stand in for the validators, with their BABE key only.
+					system::authorities()
+						.iter()
+						.map(|authority| {
+							let public = sp_core::crypto::ByteArray::to_raw_vec(authority);
+							vec![(public, sp_consensus_babe::KEY_TYPE)]
+						})
+						.collect()
+				}
+			}
+
 			impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Index> for Runtime {
 				fn account_nonce(_account: AccountId) -> Index {
 					0
@@ -213,6 +213,16 @@ pub fn validate_transaction(utx: Extrinsic) -> TransactionValidity {
 	Ok(ValidTransaction { priority: tx.amount, requires, provides, longevity: 64, propagate: true })
 }
 
+/// Set up `frame_system` for a dry run on top of the block with the given hash.
+///
+/// Block execution never initializes `frame_system`, whose block number stays at zero, so events
+/// are only recorded during a dry run.
+pub fn initialize_dry_run(block_hash: Hash) {
+	let number = <frame_system::Pallet<Runtime>>::block_number() + 1;
+	<frame_system::Pallet<Runtime>>::initialize(&number, &block_hash, &Default::default());
+	<frame_system::Pallet<Runtime>>::note_finished_initialize();
+}
+
 /// Execute a transaction outside of the block execution function.
 /// This doesn't attempt to validate anything regarding the block.
 pub fn execute_transaction(utx: Extrinsic) -> ApplyExtrinsicResult {
@@ -302,6 +312,8 @@ fn execute_transfer_backend(tx: &Transfer) -> ApplyExtrinsicResult {
 	let to_balance: u64 = storage::hashed::get_or(&blake2_256, &to_balance_key, 0);
 	storage::hashed::put(&blake2_256, &from_balance_key, &(from_balance - tx.amount));
 	storage::hashed::put(&blake2_256, &to_balance_key, &(to_balance + tx.amount));
+	// Only recorded in a dry run, see `initialize_dry_run`.
+	<frame_system::Pallet<Runtime>>::deposit_event(crate::RuntimeEvent);
 	Ok(Ok(()))
 }
 